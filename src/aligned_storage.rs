@@ -0,0 +1,84 @@
+use std::{ alloc::{ Layout, alloc, dealloc, handle_alloc_error }, ptr::NonNull };
+
+
+
+/// A fixed-size heap allocated list with a configurable alignment.
+/// Can be used as storage for the dynamic buffers when the alignment of a normal Vec is not enough, for example 64 bytes for AVX-512 or 4096 bytes for page-aligned DMA transfers.
+pub struct AlignedStorage<T> {
+	pointer:NonNull<T>,
+	length:usize,
+	layout:Layout
+}
+impl<T:Clone> AlignedStorage<T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new aligned storage with all elements set to the fill value. The actual alignment will be at least the alignment of T. Panics if the alignment is not a power of two.
+	pub fn new(length:usize, alignment:usize, fill_value:T) -> AlignedStorage<T> {
+		let layout:Layout = Layout::array::<T>(length).and_then(|layout| layout.align_to(alignment)).expect("Could not create AlignedStorage, invalid size or alignment.");
+
+		// Allocate memory. Zero-sized layouts may not be allocated, so use a dangling pointer with the right alignment instead.
+		let pointer:NonNull<T> = if layout.size() == 0 {
+			NonNull::new(layout.align() as *mut T).unwrap()
+		} else {
+			NonNull::new(unsafe { alloc(layout) } as *mut T).unwrap_or_else(|| handle_alloc_error(layout))
+		};
+
+		// Fill memory.
+		for index in 0..length {
+			unsafe { pointer.add(index).write(fill_value.clone()); }
+		}
+		AlignedStorage { pointer, length, layout }
+	}
+}
+impl<T> AlignedStorage<T> {
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Return the alignment of the start of the storage in bytes.
+	pub fn alignment(&self) -> usize {
+		self.layout.align()
+	}
+}
+impl<T> AsRef<[T]> for AlignedStorage<T> {
+	fn as_ref(&self) -> &[T] {
+		unsafe { std::slice::from_raw_parts(self.pointer.as_ptr(), self.length) }
+	}
+}
+impl<T> AsMut<[T]> for AlignedStorage<T> {
+	fn as_mut(&mut self) -> &mut [T] {
+		unsafe { std::slice::from_raw_parts_mut(self.pointer.as_ptr(), self.length) }
+	}
+}
+impl<T:Clone> Clone for AlignedStorage<T> {
+	fn clone(&self) -> AlignedStorage<T> {
+		let layout:Layout = self.layout;
+		let pointer:NonNull<T> = if layout.size() == 0 {
+			self.pointer
+		} else {
+			NonNull::new(unsafe { alloc(layout) } as *mut T).unwrap_or_else(|| handle_alloc_error(layout))
+		};
+		for (index, value) in self.as_ref().iter().enumerate() {
+			unsafe { pointer.add(index).write(value.clone()); }
+		}
+		AlignedStorage { pointer, length: self.length, layout }
+	}
+}
+impl<T:PartialEq> PartialEq for AlignedStorage<T> {
+	fn eq(&self, other:&AlignedStorage<T>) -> bool {
+		self.as_ref() == other.as_ref()
+	}
+}
+impl<T:Eq> Eq for AlignedStorage<T> {}
+impl<T> Drop for AlignedStorage<T> {
+	fn drop(&mut self) {
+		unsafe {
+			std::ptr::drop_in_place(self.as_mut() as *mut [T]);
+			if self.layout.size() != 0 {
+				dealloc(self.pointer.as_ptr() as *mut u8, self.layout);
+			}
+		}
+	}
+}
+unsafe impl<T:Send> Send for AlignedStorage<T> {}
+unsafe impl<T:Sync> Sync for AlignedStorage<T> {}
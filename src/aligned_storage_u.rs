@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+	use crate::AlignedStorage;
+
+
+
+	#[test]
+	fn test_storage_alignment() {
+		for alignment in [1, 16, 64, 4096] {
+			let storage:AlignedStorage<u8> = AlignedStorage::new(100, alignment, 0);
+			assert_eq!(storage.alignment(), alignment);
+			assert_eq!(storage.as_ref().as_ptr() as usize % alignment, 0);
+		}
+	}
+
+	#[test]
+	fn test_storage_minimum_alignment_is_type_alignment() {
+		let storage:AlignedStorage<u64> = AlignedStorage::new(10, 1, 0);
+		assert_eq!(storage.alignment(), align_of::<u64>());
+	}
+
+	#[test]
+	fn test_storage_fill_and_modify() {
+		let mut storage:AlignedStorage<i32> = AlignedStorage::new(4, 64, 7);
+		assert_eq!(storage.as_ref(), &[7, 7, 7, 7]);
+
+		storage.as_mut()[1] = 3;
+		assert_eq!(storage.as_ref(), &[7, 3, 7, 7]);
+	}
+
+	#[test]
+	fn test_storage_clone_keeps_alignment() {
+		let mut storage:AlignedStorage<String> = AlignedStorage::new(3, 256, String::from("a"));
+		storage.as_mut()[2] = String::from("b");
+
+		let cloned:AlignedStorage<String> = storage.clone();
+		assert_eq!(cloned.as_ref().as_ptr() as usize % 256, 0);
+		assert!(cloned == storage);
+	}
+
+	#[test]
+	fn test_storage_empty() {
+		let storage:AlignedStorage<i32> = AlignedStorage::new(0, 64, 0);
+		assert!(storage.as_ref().is_empty());
+		assert_eq!(storage.as_ref().as_ptr() as usize % 64, 0);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_storage_invalid_alignment() {
+		let _:AlignedStorage<i32> = AlignedStorage::new(4, 3, 0);
+	}
+}
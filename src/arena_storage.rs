@@ -0,0 +1,56 @@
+use std::mem::MaybeUninit;
+
+
+
+/// A fixed-size list carved out of a user-provided byte arena, without using the global allocator.
+/// Can be used as storage for the dynamic buffers in environments where the memory is provided up front, like embedded systems or plugin sandboxes.
+/// The elements are dropped when the storage is dropped, after which the arena can be used again.
+pub struct ArenaStorage<'a, T> {
+	data:&'a mut [T]
+}
+impl<'a, T:Clone> ArenaStorage<'a, T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Carve a storage with all elements set to the fill value out of the start of the arena. Returns the storage and the remaining unused part of the arena, or None if the arena is too small.
+	pub fn carve(arena:&'a mut [MaybeUninit<u8>], length:usize, fill_value:T) -> Option<(ArenaStorage<'a, T>, &'a mut [MaybeUninit<u8>])> {
+
+		// Find the aligned region of the arena.
+		let alignment_offset:usize = arena.as_ptr().align_offset(align_of::<T>());
+		let size:usize = size_of::<T>().checked_mul(length)?;
+		if alignment_offset.checked_add(size)? > arena.len() {
+			return None;
+		}
+		let (_, aligned_arena) = arena.split_at_mut(alignment_offset);
+		let (used_arena, remaining_arena) = aligned_arena.split_at_mut(size);
+
+		// Fill memory.
+		let pointer:*mut T = used_arena.as_mut_ptr() as *mut T;
+		for index in 0..length {
+			unsafe { pointer.add(index).write(fill_value.clone()); }
+		}
+		let data:&'a mut [T] = unsafe { std::slice::from_raw_parts_mut(pointer, length) };
+		Some((ArenaStorage { data }, remaining_arena))
+	}
+}
+impl<T> AsRef<[T]> for ArenaStorage<'_, T> {
+	fn as_ref(&self) -> &[T] {
+		self.data
+	}
+}
+impl<T> AsMut<[T]> for ArenaStorage<'_, T> {
+	fn as_mut(&mut self) -> &mut [T] {
+		self.data
+	}
+}
+impl<T:PartialEq> PartialEq for ArenaStorage<'_, T> {
+	fn eq(&self, other:&ArenaStorage<'_, T>) -> bool {
+		self.data == other.data
+	}
+}
+impl<T:Eq> Eq for ArenaStorage<'_, T> {}
+impl<T> Drop for ArenaStorage<'_, T> {
+	fn drop(&mut self) {
+		unsafe { std::ptr::drop_in_place(self.data as *mut [T]); }
+	}
+}
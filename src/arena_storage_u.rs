@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+	use crate::ArenaStorage;
+	use std::{ mem::MaybeUninit, rc::Rc };
+
+
+
+	#[test]
+	fn test_carve_alignment_and_fill() {
+		let mut arena:[MaybeUninit<u8>; 128] = [MaybeUninit::uninit(); 128];
+		let (storage, remaining_arena) = ArenaStorage::<u64>::carve(&mut arena[1..], 4, 9).unwrap();
+		assert_eq!(storage.as_ref(), &[9, 9, 9, 9]);
+		assert_eq!(storage.as_ref().as_ptr() as usize % align_of::<u64>(), 0);
+		assert!(remaining_arena.len() <= 127 - 32);
+	}
+
+	#[test]
+	fn test_carve_multiple() {
+		let mut arena:[MaybeUninit<u8>; 64] = [MaybeUninit::uninit(); 64];
+		let (mut storage_a, remaining_arena) = ArenaStorage::<u32>::carve(&mut arena, 4, 1).unwrap();
+		let (storage_b, remaining_arena) = ArenaStorage::<u32>::carve(remaining_arena, 4, 2).unwrap();
+		storage_a.as_mut()[0] = 5;
+
+		assert_eq!(storage_a.as_ref(), &[5, 1, 1, 1]);
+		assert_eq!(storage_b.as_ref(), &[2, 2, 2, 2]);
+		assert!(ArenaStorage::<u32>::carve(remaining_arena, 9, 3).is_none());
+	}
+
+	#[test]
+	fn test_carve_too_small() {
+		let mut arena:[MaybeUninit<u8>; 16] = [MaybeUninit::uninit(); 16];
+		assert!(ArenaStorage::<u32>::carve(&mut arena, 5, 0).is_none());
+	}
+
+	#[test]
+	fn test_drop_elements() {
+		let counter:Rc<()> = Rc::new(());
+		let mut arena:[MaybeUninit<u8>; 256] = [MaybeUninit::uninit(); 256];
+		{
+			let (_storage, _) = ArenaStorage::<Rc<()>>::carve(&mut arena, 8, counter.clone()).unwrap();
+			assert_eq!(Rc::strong_count(&counter), 9);
+		}
+		assert_eq!(Rc::strong_count(&counter), 1);
+	}
+}
@@ -0,0 +1,50 @@
+use crate::{ BufferRead, CircularBufferCore, SampleCopy, SingleReader };
+use std::io::{ BufRead, Read, Result };
+
+
+
+/// Reads bytes from a buffer with a single read cursor, so the buffer can be handed to code expecting a standard reader.
+/// The buffer does not implement io::Read itself, as its methods like take would then be shadowed by those of io::Read whenever that trait is in scope.
+/// An empty buffer reads as the end of the stream, so read again after more data has been written.
+pub struct BufferReader<'a, S, M> {
+	buffer:&'a mut CircularBufferCore<u8, S, SingleReader<M>>
+}
+impl<'a, S:AsRef<[u8]> + AsMut<[u8]>, M:SampleCopy<u8>> BufferReader<'a, S, M> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new reader, reading from the given buffer.
+	pub fn new(buffer:&'a mut CircularBufferCore<u8, S, SingleReader<M>>) -> BufferReader<'a, S, M> {
+		BufferReader { buffer }
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get a reference to the buffer.
+	pub fn buffer(&self) -> &CircularBufferCore<u8, S, SingleReader<M>> {
+		self.buffer
+	}
+}
+impl<S:AsRef<[u8]> + AsMut<[u8]>, M:SampleCopy<u8>> Read for BufferReader<'_, S, M> {
+	fn read(&mut self, output:&mut [u8]) -> Result<usize> {
+		Ok(self.buffer.take_to_buffer(output))
+	}
+}
+impl<S:AsRef<[u8]> + AsMut<[u8]>, M:SampleCopy<u8>> BufRead for BufferReader<'_, S, M> {
+	fn fill_buf(&mut self) -> Result<&[u8]> {
+		Ok(self.buffer.as_slices()[0])
+	}
+	fn consume(&mut self, amount:usize) {
+		self.buffer.consume(amount);
+	}
+}
+impl<S:AsRef<[u8]> + AsMut<[u8]>, M:SampleCopy<u8>> BufferRead<u8> for BufferReader<'_, S, M> {
+	fn len(&self) -> usize {
+		self.buffer.len()
+	}
+	fn take_to_buffer(&mut self, output:&mut [u8]) -> usize {
+		self.buffer.take_to_buffer(output)
+	}
+}
@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ BufferReader, CircularBuffer, CircularBufferDyn };
+	use std::io::{ BufRead, Read, Write };
+
+
+
+	#[test]
+	fn test_buffer_reader_read() {
+		let mut buffer:CircularBuffer<u8, 16> = CircularBuffer::new();
+		buffer.write_all(b"hello world").unwrap();
+
+		let mut output:String = String::new();
+		buffer.reader().read_to_string(&mut output).unwrap();
+		assert_eq!(output, "hello world");
+		assert!(buffer.is_empty());
+	}
+
+	#[test]
+	fn test_buffer_reader_buf_read_across_wrap() {
+		let mut buffer:CircularBufferDyn<u8> = CircularBufferDyn::new(8);
+		buffer.extend(b"junk");
+		buffer.take(4);
+		buffer.extend(b"ab\ncd\n");
+
+		let mut reader:BufferReader<_, _> = buffer.reader();
+		assert_eq!(reader.fill_buf().unwrap(), b"ab\nc");
+		let lines:Vec<String> = (&mut reader).lines().map(|line| line.unwrap()).collect();
+		assert_eq!(lines, vec!["ab", "cd"]);
+		assert!(reader.fill_buf().unwrap().is_empty());
+	}
+
+	#[test]
+	fn test_take_not_shadowed_by_io_read() {
+		let mut buffer:CircularBuffer<u8, 8> = CircularBuffer::new();
+		buffer.extend(&[1, 2, 3]);
+		let taken:Vec<u8> = buffer.take(2);
+		assert_eq!(taken, vec![1, 2]);
+		assert_eq!(buffer.len(), 1);
+	}
+}
@@ -0,0 +1,105 @@
+/// The amount of capacities tracked for capacity suggestions. Tracks all powers of two from 2 up to and including the largest power of two that fits in a usize.
+const CAPACITY_CANDIDATE_COUNT:usize = usize::BITS as usize - 1;
+
+
+
+/// Keeps track of the occupancy and dropped samples of a buffer.
+/// Also keeps track of how many samples would have been dropped at other capacities, which allows it to suggest a capacity based on the observed bursts.
+/// Buffers only keep statistics when the 'statistics' feature is enabled, so buffers without it stay small and writes skip the bookkeeping.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct BufferStatistics {
+	offered_count:usize,
+	written_count:usize,
+	high_water_mark:usize,
+	candidate_dropped_counts:[usize; CAPACITY_CANDIDATE_COUNT]
+}
+impl BufferStatistics {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create new empty statistics.
+	pub const fn new() -> BufferStatistics {
+		BufferStatistics {
+			offered_count: 0,
+			written_count: 0,
+			high_water_mark: 0,
+			candidate_dropped_counts: [0; CAPACITY_CANDIDATE_COUNT]
+		}
+	}
+
+
+
+	/* RECORDING METHODS */
+
+	/// Record a write to the buffer. The occupancy is the amount of stored samples before the write.
+	pub(crate) fn record_write(&mut self, occupancy:usize, offered:usize, written:usize) {
+		self.offered_count += offered;
+		self.written_count += written;
+		self.high_water_mark = self.high_water_mark.max(occupancy + written);
+
+		// Record the amount of samples that would have been dropped by smaller buffers. Buffers always keep one slot empty.
+		let demand:usize = occupancy.saturating_add(offered);
+		for (candidate_index, dropped_count) in self.candidate_dropped_counts.iter_mut().enumerate() {
+			let usable_capacity:usize = BufferStatistics::candidate_capacity(candidate_index) - 1;
+			if usable_capacity >= demand {
+				break;
+			}
+			*dropped_count += (demand - usable_capacity).min(offered);
+		}
+	}
+
+	/// Get the capacity of the candidate at the given index.
+	const fn candidate_capacity(candidate_index:usize) -> usize {
+		2 << candidate_index
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Return the total amount of samples offered to the buffer.
+	pub fn offered_count(&self) -> usize {
+		self.offered_count
+	}
+
+	/// Return the total amount of samples stored to the buffer.
+	pub fn written_count(&self) -> usize {
+		self.written_count
+	}
+
+	/// Return the total amount of samples that could not be stored because the buffer was full.
+	pub fn dropped_count(&self) -> usize {
+		self.offered_count - self.written_count
+	}
+
+	/// Return the fraction of offered samples that could not be stored because the buffer was full.
+	pub fn drop_rate(&self) -> f64 {
+		if self.offered_count == 0 {
+			0.0
+		} else {
+			self.dropped_count() as f64 / self.offered_count as f64
+		}
+	}
+
+	/// Return the highest amount of samples that was stored at once.
+	pub fn high_water_mark(&self) -> usize {
+		self.high_water_mark
+	}
+
+	/// Suggest the smallest power of two capacity that would have had a drop rate at or below the target drop rate for the observed writes.
+	/// This is an estimate, as a buffer with a different capacity would have seen different occupancies.
+	pub fn suggest_capacity(&self, target_drop_rate:f64) -> usize {
+		for (candidate_index, dropped_count) in self.candidate_dropped_counts.iter().enumerate() {
+			let drop_rate:f64 = if self.offered_count == 0 { 0.0 } else { *dropped_count as f64 / self.offered_count as f64 };
+			if drop_rate <= target_drop_rate {
+				return BufferStatistics::candidate_capacity(candidate_index);
+			}
+		}
+		BufferStatistics::candidate_capacity(CAPACITY_CANDIDATE_COUNT - 1)
+	}
+}
+impl Default for BufferStatistics {
+	fn default() -> BufferStatistics {
+		BufferStatistics::new()
+	}
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+#[cfg(feature = "statistics")]
+mod tests {
+	use crate::BufferStatistics;
+
+
+
+	#[test]
+	fn test_statistics_counts() {
+		let mut statistics:BufferStatistics = BufferStatistics::new();
+		statistics.record_write(0, 5, 5);
+		statistics.record_write(5, 5, 2);
+
+		assert_eq!(statistics.offered_count(), 10);
+		assert_eq!(statistics.written_count(), 7);
+		assert_eq!(statistics.dropped_count(), 3);
+		assert_eq!(statistics.high_water_mark(), 7);
+		assert!((statistics.drop_rate() - 0.3).abs() < 1e-9);
+	}
+
+	#[test]
+	fn test_suggest_capacity_without_writes() {
+		let statistics:BufferStatistics = BufferStatistics::new();
+		assert_eq!(statistics.drop_rate(), 0.0);
+		assert_eq!(statistics.suggest_capacity(0.0), 2);
+	}
+
+	#[test]
+	fn test_suggest_capacity_fits_largest_burst() {
+		let mut statistics:BufferStatistics = BufferStatistics::new();
+		for _ in 0..100 {
+			statistics.record_write(0, 10, 10);
+		}
+		statistics.record_write(0, 100, 100);
+
+		// Fitting all writes requires 101 slots, as one slot is always kept empty.
+		assert_eq!(statistics.suggest_capacity(0.0), 128);
+
+		// Allowing some drops makes the single large burst acceptable.
+		assert_eq!(statistics.suggest_capacity(0.1), 16);
+	}
+
+	#[test]
+	fn test_suggest_capacity_uses_occupancy() {
+		let mut statistics:BufferStatistics = BufferStatistics::new();
+		statistics.record_write(0, 6, 6);
+		statistics.record_write(6, 6, 6);
+		assert_eq!(statistics.suggest_capacity(0.0), 16);
+	}
+
+	#[test]
+	fn test_suggest_capacity_for_huge_demand() {
+		let mut statistics:BufferStatistics = BufferStatistics::new();
+		statistics.record_write(usize::MAX - 1, usize::MAX, 0);
+		assert_eq!(statistics.suggest_capacity(0.0), 1 << (usize::BITS - 1));
+	}
+}
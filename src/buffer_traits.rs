@@ -0,0 +1,122 @@
+use crate::ReadCursor;
+
+
+
+/// A buffer that samples can be written to.
+pub trait BufferWrite<T> {
+
+	/// Add a list of samples to the buffer. Returns the amount of samples stored to the buffer.
+	fn extend(&mut self, input:&[T]) -> usize;
+
+	/// Add a single sample to the buffer. Returns the amount of samples stored to the buffer.
+	fn push(&mut self, input:T) -> usize {
+		self.extend(&[input])
+	}
+}
+impl<T, B:BufferWrite<T> + ?Sized> BufferWrite<T> for &mut B {
+	fn extend(&mut self, input:&[T]) -> usize {
+		(**self).extend(input)
+	}
+}
+
+
+
+/// A buffer that samples can be read from.
+pub trait BufferRead<T> {
+
+	/// Return the amount of currently stored unread samples.
+	fn len(&self) -> usize;
+
+	/// Take an amount of samples from the buffer. Writes the data to the given output. Returns the amount of data taken from the buffer.
+	fn take_to_buffer(&mut self, output:&mut [T]) -> usize;
+
+	/// Wether or not there are 0 stored unread samples.
+	fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Take an amount of samples from the buffer.
+	fn take(&mut self, amount:usize) -> Vec<T> where T:Default + Clone {
+		let mut output_buffer:Vec<T> = vec![T::default(); amount];
+		let written_amount:usize = self.take_to_buffer(&mut output_buffer);
+		output_buffer.truncate(written_amount);
+		output_buffer
+	}
+
+	/// Take all remaining samples from the buffer.
+	fn take_all(&mut self) -> Vec<T> where T:Default + Clone {
+		self.take(self.len())
+	}
+}
+impl<T, B:BufferRead<T> + ?Sized> BufferRead<T> for &mut B {
+	fn len(&self) -> usize {
+		(**self).len()
+	}
+	fn take_to_buffer(&mut self, output:&mut [T]) -> usize {
+		(**self).take_to_buffer(output)
+	}
+}
+
+
+
+/// A buffer that unread samples can be borrowed from without copying, to be marked as read once processed.
+pub trait BufferPeek<T>:BufferRead<T> {
+
+	/// Get all unread samples as two borrowed slices, without taking them. The second slice holds the samples after the wrap and is empty if the unread samples do not wrap.
+	fn as_slices(&self) -> [&[T]; 2];
+
+	/// Mark an amount of unread samples as read without copying them. Returns the amount of samples consumed.
+	fn consume(&mut self, amount:usize) -> usize;
+
+	/// Wether or not the unread samples fill the buffer, so no more samples can arrive until some are consumed.
+	fn is_full(&self) -> bool;
+}
+impl<T, B:BufferPeek<T> + ?Sized> BufferPeek<T> for &mut B {
+	fn as_slices(&self) -> [&[T]; 2] {
+		(**self).as_slices()
+	}
+	fn consume(&mut self, amount:usize) -> usize {
+		(**self).consume(amount)
+	}
+	fn is_full(&self) -> bool {
+		(**self).is_full()
+	}
+}
+
+
+
+
+/// A buffer with multiple read cursors that samples can be read from using a specific cursor.
+pub trait CursorBufferRead<T> {
+
+	/// Return the amount of currently stored unread samples for a specific cursor.
+	fn len(&self, cursor:&ReadCursor) -> usize;
+
+	/// Take an amount of samples from the buffer using a specific cursor. Writes the data to the given output. Returns the amount of data taken from the buffer.
+	fn take_to_buffer(&mut self, output:&mut [T], cursor:&ReadCursor) -> usize;
+
+	/// Get the unread samples for a specific cursor up to the wrap of the buffer, without taking them. Only empty if there are 0 stored unread samples.
+	fn peek_contiguous(&self, cursor:&ReadCursor) -> &[T];
+
+	/// Mark an amount of unread samples as read for a specific cursor without copying them. Returns the amount of samples consumed.
+	fn consume(&mut self, amount:usize, cursor:&ReadCursor) -> usize;
+
+	/// Wether or not there are 0 stored unread samples for a specific cursor.
+	fn is_empty(&self, cursor:&ReadCursor) -> bool {
+		self.len(cursor) == 0
+	}
+}
+impl<T, B:CursorBufferRead<T> + ?Sized> CursorBufferRead<T> for &mut B {
+	fn len(&self, cursor:&ReadCursor) -> usize {
+		(**self).len(cursor)
+	}
+	fn take_to_buffer(&mut self, output:&mut [T], cursor:&ReadCursor) -> usize {
+		(**self).take_to_buffer(output, cursor)
+	}
+	fn peek_contiguous(&self, cursor:&ReadCursor) -> &[T] {
+		(**self).peek_contiguous(cursor)
+	}
+	fn consume(&mut self, amount:usize, cursor:&ReadCursor) -> usize {
+		(**self).consume(amount, cursor)
+	}
+}
\ No newline at end of file
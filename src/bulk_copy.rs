@@ -0,0 +1,115 @@
+use std::{ mem::MaybeUninit, ptr };
+
+
+
+/// The minimum amount of bytes in a copy before it is worth bypassing the cache.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+const NONTEMPORAL_THRESHOLD:usize = 64 * 1024;
+
+/// The minimum amount of bytes in a copy before it is worth issuing software prefetches.
+#[cfg(all(feature = "prefetch", target_arch = "x86_64"))]
+const PREFETCH_THRESHOLD:usize = 1024 * 1024;
+
+/// The amount of bytes prefetched ahead of the bytes being copied.
+#[cfg(all(feature = "prefetch", target_arch = "x86_64"))]
+const PREFETCH_DISTANCE:usize = 4096;
+
+/// The size of a cache line in bytes, the granularity of a prefetch.
+#[cfg(all(feature = "prefetch", target_arch = "x86_64"))]
+const CACHE_LINE_SIZE:usize = 64;
+
+
+
+/// Copy samples from the source to the destination. Both slices need to be the same length.
+/// When the 'simd' feature is enabled on x86_64, large copies will be done using non-temporal SIMD stores. This prevents large blocks of data from evicting the rest of the cache.
+/// When the 'prefetch' feature is enabled on x86_64, very large copies will prefetch the upcoming source cache lines, which speeds up reading from a cold buffer.
+#[inline]
+pub(crate) fn copy_samples<T:Copy>(destination:&mut [T], source:&[T]) {
+	assert_eq!(destination.len(), source.len(), "destination and source slices have different lengths");
+	unsafe { copy_to_pointer(destination.as_mut_ptr(), source); }
+}
+
+/// Copy samples from the source to the uninitialized destination, using the same copy paths as copy_samples. Both slices need to be the same length.
+#[inline]
+pub(crate) fn copy_samples_to_uninit<T:Copy>(destination:&mut [MaybeUninit<T>], source:&[T]) {
+	assert_eq!(destination.len(), source.len(), "destination and source slices have different lengths");
+	unsafe { copy_to_pointer(destination.as_mut_ptr().cast::<T>(), source); }
+}
+
+/// Copy samples from the source to the destination pointer, which needs to be valid for writing the length of the source.
+#[inline]
+unsafe fn copy_to_pointer<T:Copy>(destination:*mut T, source:&[T]) {
+	#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+	if size_of_val(source) >= NONTEMPORAL_THRESHOLD {
+		unsafe { copy_nontemporal(destination as *mut u8, source.as_ptr() as *const u8, size_of_val(source)); }
+		return;
+	}
+	#[cfg(all(feature = "prefetch", target_arch = "x86_64"))]
+	if size_of_val(source) >= PREFETCH_THRESHOLD {
+		unsafe { copy_prefetched(destination as *mut u8, source.as_ptr() as *const u8, size_of_val(source)); }
+		return;
+	}
+	unsafe { ptr::copy_nonoverlapping(source.as_ptr(), destination, source.len()); }
+}
+
+/// Copy bytes using non-temporal stores. The destination is aligned first, after which the data is copied in 16 byte blocks.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+unsafe fn copy_nontemporal(destination:*mut u8, source:*const u8, size:usize) {
+	use std::arch::x86_64::{ __m128i, _mm_loadu_si128, _mm_sfence, _mm_stream_si128 };
+	use std::ptr::copy_nonoverlapping;
+
+	const BLOCK_SIZE:usize = size_of::<__m128i>();
+	unsafe {
+
+		// Copy the unaligned head.
+		let head_size:usize = destination.align_offset(BLOCK_SIZE).min(size);
+		copy_nonoverlapping(source, destination, head_size);
+
+		// Stream the aligned blocks.
+		let block_count:usize = (size - head_size) / BLOCK_SIZE;
+		for block_index in 0..block_count {
+			let offset:usize = head_size + block_index * BLOCK_SIZE;
+			#[cfg(feature = "prefetch")]
+			if size >= PREFETCH_THRESHOLD && offset % CACHE_LINE_SIZE < BLOCK_SIZE && offset + PREFETCH_DISTANCE < size {
+				prefetch_line(source.add(offset + PREFETCH_DISTANCE));
+			}
+			let block:__m128i = _mm_loadu_si128(source.add(offset) as *const __m128i);
+			_mm_stream_si128(destination.add(offset) as *mut __m128i, block);
+		}
+		_mm_sfence();
+
+		// Copy the remaining tail.
+		let tail_offset:usize = head_size + block_count * BLOCK_SIZE;
+		copy_nonoverlapping(source.add(tail_offset), destination.add(tail_offset), size - tail_offset);
+	}
+}
+
+/// Copy bytes in chunks, prefetching the next chunk of the source while copying the current one.
+#[cfg(all(feature = "prefetch", target_arch = "x86_64"))]
+unsafe fn copy_prefetched(destination:*mut u8, source:*const u8, size:usize) {
+	use std::ptr::copy_nonoverlapping;
+
+	unsafe {
+		let mut offset:usize = 0;
+		while offset < size {
+			let prefetch_end:usize = (offset + 2 * PREFETCH_DISTANCE).min(size);
+			let mut prefetch_offset:usize = offset + PREFETCH_DISTANCE;
+			while prefetch_offset < prefetch_end {
+				prefetch_line(source.add(prefetch_offset));
+				prefetch_offset += CACHE_LINE_SIZE;
+			}
+			let chunk_size:usize = PREFETCH_DISTANCE.min(size - offset);
+			copy_nonoverlapping(source.add(offset), destination.add(offset), chunk_size);
+			offset += chunk_size;
+		}
+	}
+}
+
+/// Hint the processor to load the cache line holding the given address into all cache levels.
+#[cfg(all(feature = "prefetch", target_arch = "x86_64"))]
+#[inline]
+fn prefetch_line(address:*const u8) {
+	use std::arch::x86_64::{ _MM_HINT_T0, _mm_prefetch };
+
+	unsafe { _mm_prefetch::<_MM_HINT_T0>(address as *const i8); }
+}
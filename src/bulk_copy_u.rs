@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+	use crate::bulk_copy::{ copy_samples, copy_samples_to_uninit };
+
+
+
+	#[test]
+	fn test_copy_small() {
+		let source:Vec<i32> = (0..16).collect();
+		let mut destination:Vec<i32> = vec![0; 16];
+		copy_samples(&mut destination, &source);
+		assert_eq!(destination, source);
+	}
+
+	#[test]
+	fn test_copy_large() {
+		let source:Vec<u64> = (0..100_000).collect();
+		let mut destination:Vec<u64> = vec![0; 100_000];
+		copy_samples(&mut destination, &source);
+		assert_eq!(destination, source);
+	}
+
+	#[test]
+	fn test_copy_huge() {
+		let source:Vec<u32> = (0..1_000_003).collect();
+		let mut destination:Vec<u32> = vec![0; 1_000_003];
+		copy_samples(&mut destination, &source);
+		assert_eq!(destination, source);
+	}
+
+	#[test]
+	fn test_copy_large_unaligned() {
+		let source:Vec<u8> = (0..300_000).map(|index| (index % 251) as u8).collect();
+		let mut destination:Vec<u8> = vec![0; 300_010];
+		copy_samples(&mut destination[3..299_996], &source[7..]);
+		assert_eq!(destination[..3], [0, 0, 0]);
+		assert_eq!(destination[3..299_996], source[7..]);
+		assert_eq!(destination[299_996..], [0; 14]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_copy_mismatched_lengths() {
+		let source:Vec<u8> = vec![1; 200_000];
+		let mut destination:Vec<u8> = vec![0; 100_000];
+		copy_samples(&mut destination, &source);
+	}
+
+	#[test]
+	fn test_copy_to_uninit() {
+		let source:Vec<u64> = (0..100_000).collect();
+		let mut destination:Vec<u64> = Vec::with_capacity(100_000);
+		copy_samples_to_uninit(&mut destination.spare_capacity_mut()[..100_000], &source);
+		unsafe { destination.set_len(100_000); }
+		assert_eq!(destination, source);
+	}
+}
@@ -0,0 +1,57 @@
+use crate::BufferRead;
+
+
+
+/// Presents two buffers as a single readable stream, for example a replay buffer followed by a live buffer.
+/// All samples in the first buffer are read before any samples in the second buffer, so consumers do not need to handle the transition between the two.
+pub struct Chain<A, B> {
+	first:A,
+	second:B
+}
+impl<A, B> Chain<A, B> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new chain reading from the first buffer until it is empty, then from the second buffer.
+	pub fn new(first:A, second:B) -> Chain<A, B> {
+		Chain { first, second }
+	}
+
+	/// Get back the chained buffers.
+	pub fn into_inner(self) -> (A, B) {
+		(self.first, self.second)
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get a reference to the first buffer.
+	pub fn first(&self) -> &A {
+		&self.first
+	}
+
+	/// Get a reference to the second buffer.
+	pub fn second(&self) -> &B {
+		&self.second
+	}
+
+	/// Get a mutable reference to the first buffer.
+	pub fn first_mut(&mut self) -> &mut A {
+		&mut self.first
+	}
+
+	/// Get a mutable reference to the second buffer.
+	pub fn second_mut(&mut self) -> &mut B {
+		&mut self.second
+	}
+}
+impl<T, A:BufferRead<T>, B:BufferRead<T>> BufferRead<T> for Chain<A, B> {
+	fn len(&self) -> usize {
+		self.first.len() + self.second.len()
+	}
+	fn take_to_buffer(&mut self, output:&mut [T]) -> usize {
+		let first_taken:usize = self.first.take_to_buffer(output);
+		first_taken + self.second.take_to_buffer(&mut output[first_taken..])
+	}
+}
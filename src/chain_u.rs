@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ BufferRead, Chain, CircularBuffer, CircularBufferDyn };
+
+
+
+	#[test]
+	fn test_chain_reads_first_then_second() {
+		let mut history:CircularBuffer<i32, 8> = CircularBuffer::new();
+		let mut live:CircularBufferDyn<i32> = CircularBufferDyn::new(8);
+		history.extend(&[1, 2, 3]);
+		live.extend(&[4, 5]);
+
+		let mut chain:Chain<&mut CircularBuffer<i32, 8>, &mut CircularBufferDyn<i32>> = Chain::new(&mut history, &mut live);
+		assert_eq!(chain.len(), 5);
+		assert_eq!(chain.take(2), vec![1, 2]);
+		assert_eq!(chain.take(2), vec![3, 4]);
+		assert_eq!(chain.len(), 1);
+		assert_eq!(chain.take(10), vec![5]);
+		assert!(chain.is_empty());
+	}
+
+	#[test]
+	fn test_chain_live_data_after_history() {
+		let mut chain:Chain<CircularBuffer<i32, 8>, CircularBuffer<i32, 8>> = Chain::new(CircularBuffer::new(), CircularBuffer::new());
+		chain.first_mut().extend(&[1, 2]);
+		assert_eq!(chain.take_all(), vec![1, 2]);
+
+		chain.second_mut().extend(&[3, 4]);
+		assert_eq!(chain.take_all(), vec![3, 4]);
+		assert!(chain.first().is_empty());
+		assert!(chain.second().is_empty());
+	}
+
+	#[test]
+	fn test_chain_into_inner() {
+		let mut chain:Chain<CircularBuffer<i32, 8>, CircularBuffer<i32, 8>> = Chain::new(CircularBuffer::new(), CircularBuffer::new());
+		chain.first_mut().extend(&[1, 2]);
+		chain.second_mut().extend(&[3]);
+		chain.take(1);
+
+		let (mut first, mut second) = chain.into_inner();
+		assert_eq!(first.take_all(), vec![2]);
+		assert_eq!(second.take_all(), vec![3]);
+	}
+}
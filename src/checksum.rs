@@ -0,0 +1,132 @@
+use crate::{ BufferRead, BufferWrite };
+
+
+
+/// The lookup table of the reflected CRC-32 polynomial used by Ethernet, zlib and PNG.
+const CRC32_TABLE:[u32; 256] = {
+	let mut table:[u32; 256] = [0; 256];
+	let mut index:usize = 0;
+	while index < 256 {
+		let mut value:u32 = index as u32;
+		let mut bit:usize = 0;
+		while bit < 8 {
+			value = if value & 1 != 0 { (value >> 1) ^ 0xEDB8_8320 } else { value >> 1 };
+			bit += 1;
+		}
+		table[index] = value;
+		index += 1;
+	}
+	table
+};
+
+
+
+/// A rolling CRC-32 over a stream of bytes, compatible with the CRC-32 used by zlib. Feeding the stream in any amount of parts results in the same checksum.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Crc32 {
+	state:u32
+}
+impl Crc32 {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new checksum over zero bytes.
+	pub const fn new() -> Crc32 {
+		Crc32 { state: 0xFFFF_FFFF }
+	}
+
+
+
+	/* CHECKSUM METHODS */
+
+	/// Add bytes to the checksum.
+	pub fn update(&mut self, bytes:&[u8]) {
+		for byte in bytes {
+			self.state = CRC32_TABLE[((self.state ^ *byte as u32) & 0xFF) as usize] ^ (self.state >> 8);
+		}
+	}
+
+	/// Return the checksum over all bytes added so far.
+	pub fn value(&self) -> u32 {
+		!self.state
+	}
+}
+impl Default for Crc32 {
+	fn default() -> Crc32 {
+		Crc32::new()
+	}
+}
+
+
+
+/// Keeps a rolling CRC-32 over all bytes stored to and taken from a byte buffer, so both ends of a lossy transport can cheaply verify the streamed data.
+/// Comparing the written checksum of the sender with the read checksum of the receiver verifies everything that passed through. Bytes rejected by the buffer are not part of the written checksum.
+pub struct ChecksumBuffer<B> {
+	buffer:B,
+	written:Crc32,
+	read:Crc32
+}
+impl<B> ChecksumBuffer<B> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new checksum buffer around the given buffer. Bytes already in the buffer are not part of the checksums.
+	pub fn new(buffer:B) -> ChecksumBuffer<B> {
+		ChecksumBuffer {
+			buffer,
+			written: Crc32::new(),
+			read: Crc32::new()
+		}
+	}
+
+	/// Get back the wrapped buffer.
+	pub fn into_inner(self) -> B {
+		self.buffer
+	}
+
+
+
+	/* CHECKSUM METHODS */
+
+	/// Reset both checksums, for example at the start of a new transfer.
+	pub fn reset_checksums(&mut self) {
+		self.written = Crc32::new();
+		self.read = Crc32::new();
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Return the checksum over all bytes stored to the buffer.
+	pub fn checksum_written(&self) -> u32 {
+		self.written.value()
+	}
+
+	/// Return the checksum over all bytes taken from the buffer.
+	pub fn checksum_read(&self) -> u32 {
+		self.read.value()
+	}
+
+	/// Get a reference to the wrapped buffer.
+	pub fn buffer(&self) -> &B {
+		&self.buffer
+	}
+}
+impl<B:BufferWrite<u8>> BufferWrite<u8> for ChecksumBuffer<B> {
+	fn extend(&mut self, input:&[u8]) -> usize {
+		let written:usize = self.buffer.extend(input);
+		self.written.update(&input[..written]);
+		written
+	}
+}
+impl<B:BufferRead<u8>> BufferRead<u8> for ChecksumBuffer<B> {
+	fn len(&self) -> usize {
+		self.buffer.len()
+	}
+	fn take_to_buffer(&mut self, output:&mut [u8]) -> usize {
+		let taken:usize = self.buffer.take_to_buffer(output);
+		self.read.update(&output[..taken]);
+		taken
+	}
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+#[cfg(feature = "checksum")]
+mod tests {
+	use crate::{ BufferRead, BufferWrite, ChecksumBuffer, CircularBuffer, Crc32 };
+
+
+
+	#[test]
+	fn test_crc32_known_values() {
+		let mut checksum:Crc32 = Crc32::new();
+		assert_eq!(checksum.value(), 0);
+		checksum.update(b"123456789");
+		assert_eq!(checksum.value(), 0xCBF4_3926);
+	}
+
+	#[test]
+	fn test_crc32_in_parts() {
+		let mut whole:Crc32 = Crc32::new();
+		whole.update(b"The quick brown fox jumps over the lazy dog");
+		let mut parts:Crc32 = Crc32::new();
+		for part in b"The quick brown fox jumps over the lazy dog".chunks(5) {
+			parts.update(part);
+		}
+		assert_eq!(whole.value(), 0x414F_A339);
+		assert_eq!(parts, whole);
+	}
+
+	#[test]
+	fn test_written_and_read_checksums_match() {
+		let mut buffer:ChecksumBuffer<CircularBuffer<u8, 16>> = ChecksumBuffer::new(CircularBuffer::new());
+		let data:Vec<u8> = (0..200).map(|index| (index * 7) as u8).collect();
+		let mut received:Vec<u8> = Vec::new();
+		for chunk in data.chunks(10) {
+			assert_eq!(buffer.extend(chunk), chunk.len());
+			received.extend(buffer.take_all());
+		}
+		assert_eq!(received, data);
+		assert_eq!(buffer.checksum_written(), buffer.checksum_read());
+
+		let mut expected:Crc32 = Crc32::new();
+		expected.update(&data);
+		assert_eq!(buffer.checksum_read(), expected.value());
+	}
+
+	#[test]
+	fn test_rejected_bytes_are_not_checksummed() {
+		let mut buffer:ChecksumBuffer<CircularBuffer<u8, 4>> = ChecksumBuffer::new(CircularBuffer::new());
+		assert_eq!(buffer.extend(&[1, 2, 3, 4, 5]), 3);
+		let mut expected:Crc32 = Crc32::new();
+		expected.update(&[1, 2, 3]);
+		assert_eq!(buffer.checksum_written(), expected.value());
+		assert_ne!(buffer.checksum_read(), buffer.checksum_written());
+
+		buffer.take_all();
+		assert_eq!(buffer.checksum_read(), buffer.checksum_written());
+		buffer.reset_checksums();
+		assert_eq!(buffer.checksum_written(), 0);
+		assert_eq!(buffer.checksum_read(), 0);
+	}
+}
@@ -1,137 +1,141 @@
+use crate::{ BufferPeek, BufferRead, BufferReader, BulkCopySamples, CircularBufferCore, Drain, SampleCopy, SingleReader, downsample::downsample_min_max, sorted_search::{ binary_search_by_split, partition_point_split } };
+#[cfg(feature = "cobs")]
+use crate::{ InvalidCobsFrame, cobs::{ cobs_decode, cobs_encode } };
+#[cfg(feature = "dsp")]
+use crate::{ WindowFunction, WindowSample };
+use std::{ cmp::Ordering, mem::MaybeUninit };
+
+
+
 /// Keeps a list of a static size that can be written to and read from.
 /// Keeps a cursor for the last written and read bytes.
 /// This allows the buffer, once at the end of the list, to start writing at the start of the list again, provided that that data has already been written.
 /// This allows the creation of a buffer in a static variable, without always having the same amount of data in it.
-#[derive(PartialEq, Eq, Clone, Copy)]
-pub struct CircularBuffer<T, const CAPACITY:usize> {
-	buffer:[T; CAPACITY],
-	read_cursor:usize,
-	write_cursor:usize
-}
-impl<T:Copy, const CAPACITY:usize> CircularBuffer<T, CAPACITY> {
+/// Samples are copied in bulk, which allows using the bulk copy features. For samples that are only Clone, use an array with a SingleReader that clones samples, like CircularBufferCore<T, [T; CAPACITY], SingleReader>.
+pub type CircularBuffer<T, const CAPACITY:usize> = CircularBufferCore<T, [T; CAPACITY], SingleReader<BulkCopySamples>>;
+impl<T:Copy, const CAPACITY:usize> CircularBufferCore<T, [T; CAPACITY], SingleReader<BulkCopySamples>> {
 
 	/// Create a new circular buffer as compile-time constant.
 	pub const fn new_const(default_value:T) -> CircularBuffer<T, CAPACITY> {
-		CircularBuffer {
-			buffer: [default_value; CAPACITY],
-			read_cursor: 0,
-			write_cursor: 0
-		}
+		CircularBufferCore::from_parts([default_value; CAPACITY], CAPACITY, SingleReader::new())
 	}
 }
-impl<T:Default + Copy, const CAPACITY:usize> CircularBuffer<T, CAPACITY> {
-	
-	/* CONSTRUCTOR METHODS */
-
-	/// Create a new circular-buffer.
-	pub fn new() -> CircularBuffer<T, CAPACITY> {
-		CircularBuffer {
-			buffer: [T::default(); CAPACITY],
-			read_cursor: 0,
-			write_cursor: 0
-		}
+impl<T:Clone, const CAPACITY:usize, M:SampleCopy<T>> CircularBufferCore<T, [T; CAPACITY], SingleReader<M>> {
+
+	/// Create a new circular buffer pre-filled with samples generated from their index, as if they were already written, like a delay line primed with a known waveform.
+	/// As one slot is always kept empty, the buffer starts with CAPACITY - 1 unread samples.
+	pub fn new_with_fn<F:FnMut(usize) -> T>(generator:F) -> CircularBufferCore<T, [T; CAPACITY], SingleReader<M>> {
+		let mut buffer:CircularBufferCore<T, [T; CAPACITY], SingleReader<M>> = CircularBufferCore::from_parts(std::array::from_fn(generator), CAPACITY, SingleReader::new());
+		buffer.prime();
+		buffer
 	}
+}
+impl<T:Clone, S:AsRef<[T]> + AsMut<[T]>, M:SampleCopy<T>> CircularBufferCore<T, S, SingleReader<M>> {
 
+	/* BUFFER METHODS */
 
+	/// Mark all samples in the storage as written, except for the slot that is always kept empty.
+	pub(crate) fn prime(&mut self) {
+		self.write_cursor = self.capacity.saturating_sub(1);
+		self.write_sequence = self.write_cursor;
+	}
 
-	/* BUFFER METHODS */
+	/// Take one sample from the buffer. Returns the default value if the buffer is empty.
+	pub fn take_one(&mut self) -> T where T:Default {
+		self.cursor_take_one(0)
+	}
 
-	/// Add a single sample to the buffer. Returns the amount of samples stored to the buffer.
-	pub fn push(&mut self, input:T) -> usize {
-		self.extend(&[input])
+	/// Take all remaining samples from the buffer.
+	pub fn take_all(&mut self) -> Vec<T> {
+		self.take(self.len())
 	}
 
-	/// Add a list of samples to the buffer. Returns the amount of samples stored to the buffer.
-	pub fn extend(&mut self, input:&[T]) -> usize {
+	/// Take an amount of samples from the buffer.
+	pub fn take(&mut self, amount:usize) -> Vec<T> {
+		self.cursor_take(amount, 0)
+	}
 
-		// Find out how much free space is left before wrap.
-		let used_space:usize = self.len();
-		let available_space:usize = CAPACITY - used_space;
-		let required_space:usize = input.len();
+	/// Take at most max samples from the buffer, but only once at least min samples are stored. Returns None and takes nothing if less than min samples are stored. A max below min is raised to min.
+	pub fn take_at_least(&mut self, min:usize, max:usize) -> Option<Vec<T>> {
+		self.cursor_take_at_least(min, max, 0)
+	}
 
-		// If input is too large, only write beginning. Always keep one "empty" slot. This makes sure both cursors with the same value always means the buffer is empty, rather than full.
-		if required_space >= available_space {
-			return self.extend(&input[..available_space - 1]);
-		}
+	/// Take exactly N samples from the buffer into an array, without allocating. Returns None and takes nothing if less than N samples are stored.
+	pub fn take_array<const N:usize>(&mut self) -> Option<[T; N]> {
+		self.cursor_take_array(0)
+	}
 
-		// If not enough space before wrap, return or split into two modifications.
-		let available_space_before_wrap:usize = CAPACITY - self.write_cursor;
-		if available_space_before_wrap < required_space {
-			return self.extend(&input[..available_space_before_wrap]) + self.extend(&input[available_space_before_wrap..required_space]);
-		}
+	/// Take an amount of samples from the buffer. Writes the data to the given output. Returns the amount of data taken from the buffer.
+	pub fn take_to_buffer(&mut self, output:&mut [T]) -> usize {
+		self.cursor_take_to_buffer(output, 0)
+	}
 
-		// If enough space before wrap, write to buffer.
-		self.buffer[self.write_cursor..self.write_cursor + required_space].copy_from_slice(&input);
-		self.write_cursor = (self.write_cursor + required_space) % CAPACITY;
-		required_space
+	/// Take an amount of samples from the buffer. Writes the data to the given uninitialized output. Returns the amount of data taken from the buffer, which is the amount of samples at the start of the output that are initialized.
+	pub fn take_to_uninit(&mut self, output:&mut [MaybeUninit<T>]) -> usize {
+		self.cursor_take_to_uninit(output, 0)
 	}
 
-	/// Take one sample from the buffer.
-	pub fn take_one(&mut self) -> T {
-		let found:Vec<T> = self.take(1);
-		if found.is_empty() {
-			T::default()
-		} else {
-			found[0]
-		}
+	/// Take a window of samples from the buffer, while only consuming the hop size, as used in STFT and FFT pipelines. Writes the window to the start of the output.
+	/// Returns false and takes nothing if less than the window or hop size is stored, or if the output is smaller than the window.
+	pub fn take_windowed(&mut self, window:usize, hop:usize, output:&mut [T]) -> bool {
+		self.cursor_take_windowed(window, hop, output, 0)
 	}
 
-	/// Take all remaining samples from the buffer.
-	pub fn take_all(&mut self) -> Vec<T> {
-		self.take(self.len())
+	/// Get all unread samples as two borrowed slices, without taking them. The second slice holds the samples after the wrap and is empty if the unread samples do not wrap.
+	pub fn as_slices(&self) -> [&[T]; 2] {
+		self.cursor_unread_slices(0)
 	}
 
-	/// Take an amount of samples from the buffer.
-	pub fn take(&mut self, amount:usize) -> Vec<T> {
-		let mut output_buffer:Vec<T> = vec![T::default(); amount];
-		let written_amount:usize = self.take_to_buffer(&mut output_buffer);
-		output_buffer[..written_amount].to_vec()
+	/// Get at most the given amount of the oldest unread samples as two borrowed slices, without taking them. Combined with consume, this allows reading without copying.
+	pub fn peek(&self, amount:usize) -> [&[T]; 2] {
+		self.cursor_peek(amount, 0)
 	}
 
-	/// Take an amount of samples from the buffer. Writes the data to the given output. Returns the amount of data taken from the buffer.
-	fn take_to_buffer(&mut self, output:&mut [T]) -> usize {
+	/// Mark an amount of unread samples as read without copying them, for example after processing the slices from peek. Returns the amount of samples consumed.
+	pub fn consume(&mut self, amount:usize) -> usize {
+		self.cursor_consume(amount, 0)
+	}
 
-		// Find out how much free space is left before wrap.
-		let used_space:usize = self.len();
-		if used_space == 0 {
-			return 0;
-		}
-		let used_required_space:usize = used_space.min(output.len());
-
-		// Take straight part.
-		let straight_space:usize = used_required_space.min(CAPACITY - self.read_cursor);
-		output[..straight_space].copy_from_slice(&self.buffer[self.read_cursor..self.read_cursor + straight_space]);
-		self.read_cursor += straight_space;
-		let wrapped_space:usize = used_required_space - straight_space;
-		if wrapped_space != 0 {
-			self.read_cursor -= CAPACITY;
-			output[straight_space..straight_space + wrapped_space].copy_from_slice(&self.buffer[self.read_cursor..self.read_cursor + wrapped_space]);
-			self.read_cursor += wrapped_space;
-		}
-		
-		// Return taken amount.
-		straight_space + wrapped_space
+	/// Create an iterator that takes all unread samples one by one, oldest first, without allocating. Samples not iterated over are taken when the iterator is dropped.
+	pub fn drain(&mut self) -> Drain<'_, T, S, SingleReader<M>> {
+		self.cursor_drain(0)
+	}
+
+	/// Drop the oldest unread samples while the predicate returns true for them, without copying them out. Stops at the first sample for which the predicate returns false. Returns the amount of dropped samples.
+	pub fn evict_while<F:FnMut(&T) -> bool>(&mut self, predicate:F) -> usize {
+		self.cursor_evict_while(predicate, 0)
+	}
+
+	/// Get the minimum and maximum of the unread samples in each of the given amount of buckets, without taking them. Allows rendering a large buffer to a limited amount of pixels.
+	/// If fewer samples than buckets are stored, each bucket holds a single sample.
+	pub fn downsample(&self, buckets:usize) -> Vec<(T, T)> where T:PartialOrd {
+		downsample_min_max(self.cursor_unread_slices(0), buckets)
+	}
+
+	/// Get the amount of unread samples for which the predicate returns true, for unread samples sorted such that all samples matching the predicate come first. Runs in O(log n).
+	/// Combined with take, this allows skipping to the first sample after a timestamp or sequence number without checking every sample.
+	pub fn partition_point<F:FnMut(&T) -> bool>(&self, predicate:F) -> usize {
+		partition_point_split(self.cursor_unread_slices(0), predicate)
+	}
+
+	/// Binary search the sorted unread samples using a comparator function, without taking them. Returns the index relative to the oldest unread sample, or the index where a matching sample could be inserted.
+	pub fn binary_search_by<F:FnMut(&T) -> Ordering>(&self, comparator:F) -> Result<usize, usize> {
+		binary_search_by_split(self.cursor_unread_slices(0), comparator)
 	}
 
 	/// Get all data that is written in the buffer, including the amount already having been read. The newest samples will be at the end of the list.
 	pub fn raw_data(&self) -> Vec<T> {
-		let mut output:Vec<T> = self.buffer.to_vec();
-		output.rotate_left(self.read_cursor);
-		output
+		self.cursor_raw_data(0)
 	}
 
 
 
-	
+
 	/* PROPERTY GETTER METHODS */
 
 	/// Return the amount of currently stored samples.
 	pub fn len(&self) -> usize {
-		if self.write_cursor >= self.read_cursor {
-			self.write_cursor - self.read_cursor
-		} else {
-			CAPACITY - (self.read_cursor - self.write_cursor)
-		}.min(CAPACITY)
+		self.cursor_len(0)
 	}
 
 	/// Wether or not there are 0 stored samples.
@@ -141,6 +145,87 @@ impl<T:Default + Copy, const CAPACITY:usize> CircularBuffer<T, CAPACITY> {
 
 	/// Wether or not the buffer is full.
 	pub fn is_full(&self) -> bool {
-		self.len() == CAPACITY - 1
+		self.len() == self.capacity - 1
+	}
+}
+impl<T:Default + Copy, const CAPACITY:usize, M:SampleCopy<T>> FromIterator<T> for CircularBufferCore<T, [T; CAPACITY], SingleReader<M>> {
+
+	/// Create a new circular buffer holding the samples of the iterator. Samples that do not fit are dropped.
+	fn from_iter<I:IntoIterator<Item = T>>(input:I) -> CircularBufferCore<T, [T; CAPACITY], SingleReader<M>> {
+		let mut buffer:CircularBufferCore<T, [T; CAPACITY], SingleReader<M>> = CircularBufferCore::<T, [T; CAPACITY], SingleReader<M>>::new();
+		Extend::extend(&mut buffer, input);
+		buffer
+	}
+}
+impl<T:Clone, S:AsRef<[T]> + AsMut<[T]>, M:SampleCopy<T>> BufferRead<T> for CircularBufferCore<T, S, SingleReader<M>> {
+	fn len(&self) -> usize {
+		self.len()
+	}
+	fn take_to_buffer(&mut self, output:&mut [T]) -> usize {
+		self.take_to_buffer(output)
+	}
+}
+impl<T:Clone, S:AsRef<[T]> + AsMut<[T]>, M:SampleCopy<T>> BufferPeek<T> for CircularBufferCore<T, S, SingleReader<M>> {
+	fn as_slices(&self) -> [&[T]; 2] {
+		self.as_slices()
+	}
+	fn consume(&mut self, amount:usize) -> usize {
+		self.consume(amount)
+	}
+	fn is_full(&self) -> bool {
+		self.is_full()
+	}
+}
+impl<S:AsRef<[u8]> + AsMut<[u8]>, M:SampleCopy<u8>> CircularBufferCore<u8, S, SingleReader<M>> {
+
+	/* IO METHODS */
+
+	/// Create a reader that implements io::Read and io::BufRead, taking bytes from the buffer.
+	pub fn reader(&mut self) -> BufferReader<'_, S, M> {
+		BufferReader::new(self)
+	}
+}
+#[cfg(feature = "dsp")]
+impl<T:Clone + WindowSample, S:AsRef<[T]> + AsMut<[T]>, M:SampleCopy<T>> CircularBufferCore<T, S, SingleReader<M>> {
+
+	/* DSP METHODS */
+
+	/// Take a window of samples from the buffer multiplied by the window function, while only consuming the hop size. Writes the window to the start of the output.
+	/// Returns false and takes nothing if less than the window or hop size is stored, or if the output is smaller than the window.
+	pub fn take_windowed_with(&mut self, window:usize, hop:usize, output:&mut [T], function:WindowFunction) -> bool {
+		self.cursor_take_windowed_with(window, hop, output, function, 0)
+	}
+}
+#[cfg(feature = "cobs")]
+impl<S:AsRef<[u8]> + AsMut<[u8]>, M:SampleCopy<u8>> CircularBufferCore<u8, S, SingleReader<M>> {
+
+	/* COBS METHODS */
+
+	/// Encode a frame using COBS and add it to the buffer, including the zero delimiter, only if the whole encoded frame fits. Returns wether or not the frame was stored.
+	pub fn push_cobs_frame(&mut self, frame:&[u8]) -> bool {
+		let encoded:Vec<u8> = cobs_encode(frame);
+		self.reject_unless_fits(encoded.len()) && self.extend(&encoded) == encoded.len()
+	}
+
+	/// Take the next complete COBS frame from the buffer, like one written byte by byte by a serial interrupt handler, and decode it. Returns None and takes nothing if no complete frame is stored yet.
+	/// A frame that can not be decoded is taken and reported as invalid. Empty frames between delimiters are skipped. As a full buffer without a delimiter can never hold a complete frame, its data is taken and reported as invalid as well.
+	pub fn take_cobs_frame(&mut self) -> Option<Result<Vec<u8>, InvalidCobsFrame>> {
+		loop {
+			let [straight_samples, wrapped_samples] = self.cursor_unread_slices(0);
+			let Some(delimiter_index) = straight_samples.iter().chain(wrapped_samples).position(|byte| *byte == 0) else {
+				if !self.is_full() {
+					return None;
+				}
+				let dropped_amount:usize = self.len();
+				self.evict_while(|_| true);
+				return Some(Err(InvalidCobsFrame(dropped_amount)));
+			};
+			if delimiter_index == 0 {
+				self.evict_while(|byte| *byte == 0);
+				continue;
+			}
+			let encoded:Vec<u8> = self.take(delimiter_index + 1);
+			return Some(cobs_decode(&encoded[..delimiter_index]).ok_or(InvalidCobsFrame(delimiter_index)));
+		}
 	}
 }
\ No newline at end of file
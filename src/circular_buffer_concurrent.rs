@@ -0,0 +1,565 @@
+use crate::{ BufferPeek, BufferRead, BufferWrite, Clock, WaitStrategy, clock::ClockRef, wait_strategy::WaiterSlot };
+use std::{ cell::UnsafeCell, ptr, slice, sync::{ Arc, atomic::{ AtomicBool, AtomicUsize, Ordering } }, thread, time::Duration };
+
+
+
+/// The read cursor value of a consumer that was dropped, which the producer no longer waits for.
+const DROPPED_CURSOR:usize = usize::MAX;
+
+
+
+/// The error of writing to a concurrent buffer once all consumers are dropped, as nobody will ever read the samples.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Disconnected;
+
+
+
+/// A circular buffer that one producer thread can write to while any amount of consumer threads read from it, without locks.
+/// Split the buffer into a Producer and Consumers to use it. Each consumer keeps its own read cursor, like the cursors of a CircularBufferMultiRead, so every consumer receives all samples.
+/// Like the other buffers, one slot is always kept empty, so the buffer holds at most CAPACITY - 1 samples. The producer can only overwrite samples all consumers have read.
+pub struct CircularBufferConcurrent<T:Copy, const CAPACITY:usize> {
+	buffer:UnsafeCell<[T; CAPACITY]>,
+	write_cursor:AtomicUsize,
+	write_sequence:AtomicUsize,
+	read_cursors:Box<[AtomicUsize]>,
+	producer_alive:AtomicBool,
+	poisoned:AtomicBool,
+	consumer_waiters:Box<[WaiterSlot]>,
+	producer_waiter:WaiterSlot
+}
+impl<T:Copy, const CAPACITY:usize> CircularBufferConcurrent<T, CAPACITY> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new concurrent circular-buffer with all slots filled with the given value.
+	/// As one slot is always kept empty, the capacity needs to be at least 2, which is checked at compile time.
+	/// ```compile_fail
+	/// let buffer = circular_buffer::CircularBufferConcurrent::<u8, 1>::new_with(0);
+	/// ```
+	pub fn new_with(fill:T) -> CircularBufferConcurrent<T, CAPACITY> {
+		const { assert!(CAPACITY >= 2, "CircularBufferConcurrent needs a capacity of at least 2, as one slot is always kept empty.") };
+		CircularBufferConcurrent {
+			buffer: UnsafeCell::new([fill; CAPACITY]),
+			write_cursor: AtomicUsize::new(0),
+			write_sequence: AtomicUsize::new(0),
+			read_cursors: Box::new([]),
+			producer_alive: AtomicBool::new(true),
+			poisoned: AtomicBool::new(false),
+			consumer_waiters: Box::new([]),
+			producer_waiter: WaiterSlot::new()
+		}
+	}
+
+	/// Split the buffer into a producer and the given amount of consumers, which can be moved to different threads.
+	pub fn split(mut self, consumer_count:usize) -> (Producer<T, CAPACITY>, Vec<Consumer<T, CAPACITY>>) {
+		self.read_cursors = (0..consumer_count).map(|_| AtomicUsize::new(0)).collect();
+		self.consumer_waiters = (0..consumer_count).map(|_| WaiterSlot::new()).collect();
+		let shared:Arc<CircularBufferConcurrent<T, CAPACITY>> = Arc::new(self);
+		let consumers:Vec<Consumer<T, CAPACITY>> = (0..consumer_count).map(|cursor_index| Consumer { shared: Arc::clone(&shared), cursor_index, clock: ClockRef::SYSTEM, wait_strategy: WaitStrategy::default() }).collect();
+		(Producer { shared, wait_strategy: WaitStrategy::default() }, consumers)
+	}
+
+
+
+	/* BUFFER METHODS */
+
+	/// Return a pointer to the first sample of the storage. Samples are only accessed through raw pointers, as the producer and consumers access different parts of the storage at the same time.
+	fn samples(&self) -> *mut T {
+		self.buffer.get().cast::<T>()
+	}
+
+	/// Mark the buffer as poisoned if the producer or a consumer is dropped by a panic.
+	fn poison_if_panicking(&self) {
+		if thread::panicking() {
+			self.poisoned.store(true, Ordering::Release);
+		}
+	}
+
+	/// Return the amount of unread samples between a read cursor and the write cursor.
+	fn used_space(write_cursor:usize, read_cursor:usize) -> usize {
+		(write_cursor + CAPACITY - read_cursor) % CAPACITY
+	}
+}
+impl<T:Default + Copy, const CAPACITY:usize> CircularBufferConcurrent<T, CAPACITY> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new concurrent circular-buffer.
+	pub fn new() -> CircularBufferConcurrent<T, CAPACITY> {
+		CircularBufferConcurrent::new_with(T::default())
+	}
+}
+impl<T:Default + Copy, const CAPACITY:usize> Default for CircularBufferConcurrent<T, CAPACITY> {
+	fn default() -> CircularBufferConcurrent<T, CAPACITY> {
+		CircularBufferConcurrent::new()
+	}
+}
+// Consumers on different threads borrow the same samples at the same time through peek and as_slices, so samples need to be Sync as well.
+unsafe impl<T:Copy + Send + Sync, const CAPACITY:usize> Send for CircularBufferConcurrent<T, CAPACITY> {}
+unsafe impl<T:Copy + Send + Sync, const CAPACITY:usize> Sync for CircularBufferConcurrent<T, CAPACITY> {}
+
+
+
+/// The writing half of a CircularBufferConcurrent. There is only one producer per buffer.
+pub struct Producer<T:Copy, const CAPACITY:usize> {
+	shared:Arc<CircularBufferConcurrent<T, CAPACITY>>,
+	wait_strategy:WaitStrategy
+}
+impl<T:Copy, const CAPACITY:usize> Producer<T, CAPACITY> {
+
+	/* BUFFER METHODS */
+
+	/// Add a single sample to the buffer. Returns the amount of samples stored to the buffer.
+	pub fn push(&mut self, input:T) -> usize {
+		self.extend(&[input])
+	}
+
+	/// Add a list of samples to the buffer, without waiting for consumers. Returns the amount of samples stored to the buffer, which is less than the input if the slowest consumer has not read enough.
+	/// Nothing is stored once the producer is closed.
+	pub fn extend(&mut self, input:&[T]) -> usize {
+		if self.is_closed() {
+			return 0;
+		}
+		let write_cursor:usize = self.shared.write_cursor.load(Ordering::Relaxed);
+		let written:usize = input.len().min(self.free_space());
+		let straight_space:usize = written.min(CAPACITY - write_cursor);
+		unsafe {
+			ptr::copy_nonoverlapping(input.as_ptr(), self.shared.samples().add(write_cursor), straight_space);
+			ptr::copy_nonoverlapping(input.as_ptr().add(straight_space), self.shared.samples(), written - straight_space);
+		}
+		if written != 0 {
+			self.shared.write_cursor.store((write_cursor + written) % CAPACITY, Ordering::Release);
+			self.shared.write_sequence.fetch_add(written, Ordering::Release);
+			WaiterSlot::wake_all(&self.shared.consumer_waiters);
+		}
+		written
+	}
+
+	/// Add a list of samples to the buffer like extend, but fail once all consumers are dropped, so the producer does not keep filling a buffer nobody reads.
+	pub fn try_extend(&mut self, input:&[T]) -> Result<usize, Disconnected> {
+		if !self.is_peer_alive() {
+			return Err(Disconnected);
+		}
+		Ok(self.extend(input))
+	}
+
+	/// Add all samples to the buffer, waiting for the slowest consumer using the wait strategy whenever the buffer is full.
+	/// Returns the amount of samples stored to the buffer, which is less than the input only once all consumers are dropped.
+	pub fn extend_blocking(&mut self, input:&[T], wait_strategy:WaitStrategy) -> usize {
+		let shared:Arc<CircularBufferConcurrent<T, CAPACITY>> = Arc::clone(&self.shared);
+		let mut written:usize = 0;
+		shared.producer_waiter.wait_for(wait_strategy, || self.poll_extend(input, &mut written));
+		written
+	}
+
+	/// Add all samples to the buffer, returning Pending whenever the buffer is full until a consumer has read. Returns the amount of samples stored to the buffer, which is less than the input only once all consumers are dropped.
+	pub async fn extend_async(&mut self, input:&[T]) -> usize {
+		let shared:Arc<CircularBufferConcurrent<T, CAPACITY>> = Arc::clone(&self.shared);
+		let mut written:usize = 0;
+		shared.producer_waiter.wait_async(|| self.poll_extend(input, &mut written)).await;
+		written
+	}
+
+	/// Store samples from the part of the input after the already written amount. Returns Some once all input is written, all consumers are dropped or the producer is closed.
+	fn poll_extend(&mut self, input:&[T], written:&mut usize) -> Option<()> {
+		let consumers_dropped:bool = !self.is_peer_alive();
+		*written += self.extend(&input[*written..]);
+		(*written == input.len() || consumers_dropped || self.is_closed()).then_some(())
+	}
+
+	/// End the stream. Consumers can still take the samples written before, after which their reads return the end of the stream instead of waiting. Writes after closing store nothing.
+	/// Dropping the producer closes it as well.
+	pub fn close(&mut self) {
+		self.shared.producer_alive.store(false, Ordering::Release);
+		WaiterSlot::wake_all(&self.shared.consumer_waiters);
+	}
+
+	/// Wait using the wait strategy until at least one sample can be written. Returns false if nothing can be written anymore, as all consumers are dropped or the producer is closed.
+	pub fn wait_for_space(&self, wait_strategy:WaitStrategy) -> bool {
+		self.shared.producer_waiter.wait_for(wait_strategy, || self.poll_space())
+	}
+
+	/// Wait until at least one sample can be written, returning Pending until a consumer has read. Returns false if nothing can be written anymore, as all consumers are dropped or the producer is closed.
+	pub async fn wait_for_space_async(&self) -> bool {
+		self.shared.producer_waiter.wait_async(|| self.poll_space()).await
+	}
+
+	/// Wait using the wait strategy until all consumers have read all samples, like before shutting down a pipeline.
+	pub fn wait_until_read(&self, wait_strategy:WaitStrategy) {
+		self.shared.producer_waiter.wait_for(wait_strategy, || self.poll_read());
+	}
+
+	/// Wait until all consumers have read all samples, returning Pending until they have.
+	pub async fn wait_until_read_async(&self) {
+		self.shared.producer_waiter.wait_async(|| self.poll_read()).await;
+	}
+
+	/// Returns Some with wether or not samples can be written once there is free space, all consumers are dropped or the producer is closed.
+	fn poll_space(&self) -> Option<bool> {
+		if self.is_closed() || !self.is_peer_alive() {
+			Some(false)
+		} else {
+			(self.free_space() != 0).then_some(true)
+		}
+	}
+
+	/// Returns Some once the consumers that are not dropped have read all samples.
+	fn poll_read(&self) -> Option<()> {
+		(self.free_space() == CAPACITY - 1).then_some(())
+	}
+
+
+
+	/* PROPERTY SETTER METHODS */
+
+	/// Set the wait strategy for writes that do not take one, like writes through the embedded-io traits. Uses the default wait strategy by default.
+	pub fn set_wait_strategy(&mut self, wait_strategy:WaitStrategy) {
+		self.wait_strategy = wait_strategy;
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Return the amount of samples that can be written before the slowest consumer needs to read.
+	pub fn free_space(&self) -> usize {
+		let write_cursor:usize = self.shared.write_cursor.load(Ordering::Relaxed);
+		let largest_used_space:usize = self.shared.read_cursors.iter()
+			.map(|read_cursor| read_cursor.load(Ordering::Acquire))
+			.filter(|read_cursor| *read_cursor != DROPPED_CURSOR)
+			.map(|read_cursor| CircularBufferConcurrent::<T, CAPACITY>::used_space(write_cursor, read_cursor))
+			.max().unwrap_or_default();
+		CAPACITY.saturating_sub(largest_used_space + 1)
+	}
+
+	/// Return the write sequence, the total amount of samples ever stored to the buffer. A sample is visible to consumers once the write sequence has passed its position.
+	pub fn write_sequence(&self) -> usize {
+		self.shared.write_sequence.load(Ordering::Relaxed)
+	}
+
+	/// Wether or not any consumer has not been dropped yet.
+	pub fn is_peer_alive(&self) -> bool {
+		self.shared.read_cursors.iter().any(|read_cursor| read_cursor.load(Ordering::Acquire) != DROPPED_CURSOR)
+	}
+
+	/// Return the wait strategy for writes that do not take one.
+	pub fn wait_strategy(&self) -> WaitStrategy {
+		self.wait_strategy
+	}
+
+	/// Wether or not the producer was closed, ending the stream.
+	pub fn is_closed(&self) -> bool {
+		!self.shared.producer_alive.load(Ordering::Relaxed)
+	}
+
+	/// Wether or not a consumer was dropped by a panic, so it may have stopped in the middle of processing samples it had not consumed yet.
+	pub fn is_poisoned(&self) -> bool {
+		self.shared.poisoned.load(Ordering::Acquire)
+	}
+}
+impl<T:Copy, const CAPACITY:usize> Drop for Producer<T, CAPACITY> {
+
+	// A producer that panics ends the stream like a closed producer, so waiting consumers wake up and can tell the difference using is_poisoned.
+	fn drop(&mut self) {
+		self.shared.poison_if_panicking();
+		self.close();
+	}
+}
+impl<T:Copy, const CAPACITY:usize> BufferWrite<T> for Producer<T, CAPACITY> {
+	fn extend(&mut self, input:&[T]) -> usize {
+		self.extend(input)
+	}
+}
+
+
+
+/// A reading half of a CircularBufferConcurrent, with its own read cursor. Dropping a consumer stops the producer from waiting for it.
+/// Closing or dropping the producer ends the stream, which stops the blocking reads from waiting.
+pub struct Consumer<T:Copy, const CAPACITY:usize> {
+	shared:Arc<CircularBufferConcurrent<T, CAPACITY>>,
+	cursor_index:usize,
+	clock:ClockRef,
+	wait_strategy:WaitStrategy
+}
+impl<T:Copy, const CAPACITY:usize> Consumer<T, CAPACITY> {
+
+	/* BUFFER METHODS */
+
+	/// Take an amount of samples from the buffer, without waiting for the producer. Writes the data to the given output. Returns the amount of data taken from the buffer.
+	pub fn take_to_buffer(&mut self, output:&mut [T]) -> usize {
+		unsafe { self.take_to_pointer(output.as_mut_ptr(), output.len()) }
+	}
+
+	/// Take an amount of samples from the buffer, without waiting for the producer.
+	pub fn take(&mut self, amount:usize) -> Vec<T> {
+		let amount:usize = amount.min(self.len());
+		let mut output:Vec<T> = Vec::with_capacity(amount);
+		unsafe {
+			let taken:usize = self.take_to_pointer(output.as_mut_ptr(), amount);
+			output.set_len(taken);
+		}
+		output
+	}
+
+	/// Copy at most the given amount of samples to the output pointer and move the read cursor past them. Returns the amount of samples copied.
+	/// The output pointer must be valid for writing the given amount of samples.
+	unsafe fn take_to_pointer(&mut self, output:*mut T, amount:usize) -> usize {
+		let read_cursor:usize = self.shared.read_cursors[self.cursor_index].load(Ordering::Relaxed);
+		let taken:usize = amount.min(self.len());
+		let straight_space:usize = taken.min(CAPACITY - read_cursor);
+		unsafe {
+			ptr::copy_nonoverlapping(self.shared.samples().add(read_cursor), output, straight_space);
+			ptr::copy_nonoverlapping(self.shared.samples(), output.add(straight_space), taken - straight_space);
+		}
+		if taken != 0 {
+			self.shared.read_cursors[self.cursor_index].store((read_cursor + taken) % CAPACITY, Ordering::Release);
+			WaiterSlot::wake_all(slice::from_ref(&self.shared.producer_waiter));
+		}
+		taken
+	}
+
+	/// Take all samples currently stored in the buffer.
+	pub fn take_all(&mut self) -> Vec<T> {
+		self.take(self.len())
+	}
+
+	/// Get all unread samples as two borrowed slices, without taking them. The second slice holds the samples after the wrap and is empty if the unread samples do not wrap.
+	/// Samples written by the producer after this call are not included. The producer can not overwrite the borrowed samples, as they are only freed by consuming them.
+	pub fn as_slices(&self) -> [&[T]; 2] {
+		self.peek(usize::MAX)
+	}
+
+	/// Get at most the given amount of the oldest unread samples as two borrowed slices, without taking them. Combined with consume, this allows reading without copying.
+	pub fn peek(&self, amount:usize) -> [&[T]; 2] {
+		let read_cursor:usize = self.shared.read_cursors[self.cursor_index].load(Ordering::Relaxed);
+		let amount:usize = amount.min(self.len());
+		let straight_space:usize = amount.min(CAPACITY - read_cursor);
+		unsafe {[
+			slice::from_raw_parts(self.shared.samples().add(read_cursor), straight_space),
+			slice::from_raw_parts(self.shared.samples(), amount - straight_space)
+		]}
+	}
+
+	/// Mark an amount of unread samples as read without copying them, for example after processing the slices from peek. Returns the amount of samples consumed.
+	pub fn consume(&mut self, amount:usize) -> usize {
+		let read_cursor:usize = self.shared.read_cursors[self.cursor_index].load(Ordering::Relaxed);
+		let consumed:usize = amount.min(self.len());
+		if consumed != 0 {
+			self.shared.read_cursors[self.cursor_index].store((read_cursor + consumed) % CAPACITY, Ordering::Release);
+			WaiterSlot::wake_all(slice::from_ref(&self.shared.producer_waiter));
+		}
+		consumed
+	}
+
+	/// Create an iterator that takes the samples currently unread one by one, oldest first, without allocating. Samples not iterated over are taken when the iterator is dropped.
+	/// Samples written by the producer while iterating are left for later reads.
+	pub fn drain(&mut self) -> ConsumerDrain<'_, T, CAPACITY> {
+		let remaining:usize = self.len();
+		ConsumerDrain { consumer: self, remaining }
+	}
+
+	/// Fill the whole output with samples, waiting for the producer using the wait strategy whenever the buffer is empty.
+	/// Returns the amount of samples taken, which is less than the output only once the producer is closed and all its samples are taken, marking the end of the stream.
+	pub fn take_blocking(&mut self, output:&mut [T], wait_strategy:WaitStrategy) -> usize {
+		let shared:Arc<CircularBufferConcurrent<T, CAPACITY>> = Arc::clone(&self.shared);
+		let mut taken:usize = 0;
+		shared.consumer_waiters[self.cursor_index].wait_for(wait_strategy, || self.poll_take(output, &mut taken));
+		taken
+	}
+
+	/// Fill the output with samples, waiting for the producer using the wait strategy whenever the buffer is empty, for at most the given timeout on the clock of the consumer.
+	/// Returns the amount of samples taken, which is less than the output if the timeout passed or the producer was closed.
+	pub fn take_timeout(&mut self, output:&mut [T], timeout:Duration, wait_strategy:WaitStrategy) -> usize {
+		let shared:Arc<CircularBufferConcurrent<T, CAPACITY>> = Arc::clone(&self.shared);
+		let clock:&dyn Clock = self.clock.0;
+		let mut taken:usize = 0;
+		let _ = shared.consumer_waiters[self.cursor_index].wait_for_timeout(wait_strategy, timeout, clock, || self.poll_take(output, &mut taken));
+		taken
+	}
+
+	/// Fill the whole output with samples, returning Pending whenever the buffer is empty until the producer has written.
+	/// Returns the amount of samples taken, which is less than the output only once the producer is closed and all its samples are taken, marking the end of the stream.
+	pub async fn take_async(&mut self, output:&mut [T]) -> usize {
+		let shared:Arc<CircularBufferConcurrent<T, CAPACITY>> = Arc::clone(&self.shared);
+		let mut taken:usize = 0;
+		shared.consumer_waiters[self.cursor_index].wait_async(|| self.poll_take(output, &mut taken)).await;
+		taken
+	}
+
+	/// Wait using the wait strategy until the write with the given sequence has become visible, like the response to a request written to another ring.
+	/// Returns false if the producer was closed before writing it.
+	pub fn wait_until_sequence(&self, sequence:usize, wait_strategy:WaitStrategy) -> bool {
+		self.shared.consumer_waiters[self.cursor_index].wait_for(wait_strategy, || self.poll_sequence(sequence))
+	}
+
+	/// Wait using the wait strategy until the write with the given sequence has become visible, for at most the given timeout on the clock of the consumer.
+	/// Returns false if the timeout passed or the producer was closed before writing it.
+	pub fn wait_until_sequence_timeout(&self, sequence:usize, timeout:Duration, wait_strategy:WaitStrategy) -> bool {
+		self.shared.consumer_waiters[self.cursor_index].wait_for_timeout(wait_strategy, timeout, self.clock.0, || self.poll_sequence(sequence)).unwrap_or(false)
+	}
+
+	/// Wait until the write with the given sequence has become visible, returning Pending until the producer has written it.
+	/// Returns false if the producer was closed before writing it.
+	pub async fn wait_until_sequence_async(&self, sequence:usize) -> bool {
+		self.shared.consumer_waiters[self.cursor_index].wait_async(|| self.poll_sequence(sequence)).await
+	}
+
+	/// Wait using the wait strategy until there are unread samples. Returns false if the stream ended instead.
+	pub fn wait_for_data(&self, wait_strategy:WaitStrategy) -> bool {
+		self.shared.consumer_waiters[self.cursor_index].wait_for(wait_strategy, || self.poll_data())
+	}
+
+	/// Wait until there are unread samples, returning Pending until the producer has written. Returns false if the stream ended instead.
+	pub async fn wait_for_data_async(&self) -> bool {
+		self.shared.consumer_waiters[self.cursor_index].wait_async(|| self.poll_data()).await
+	}
+
+	/// Returns Some with wether or not there are unread samples once there are, or the producer is closed.
+	fn poll_data(&self) -> Option<bool> {
+		let producer_closed:bool = !self.is_peer_alive();
+		if !self.is_empty() {
+			Some(true)
+		} else {
+			producer_closed.then_some(false)
+		}
+	}
+
+	/// Returns Some with the visibility of the sequence once it is visible or the producer is closed.
+	fn poll_sequence(&self, sequence:usize) -> Option<bool> {
+		let producer_closed:bool = !self.is_peer_alive();
+		if self.is_sequence_visible(sequence) {
+			Some(true)
+		} else {
+			producer_closed.then_some(false)
+		}
+	}
+
+	/// Take samples into the part of the output after the already taken amount. Returns Some once the output is full or the producer is closed.
+	fn poll_take(&mut self, output:&mut [T], taken:&mut usize) -> Option<()> {
+		let producer_closed:bool = !self.is_peer_alive();
+		*taken += self.take_to_buffer(&mut output[*taken..]);
+		(*taken == output.len() || producer_closed).then_some(())
+	}
+
+
+
+	/* PROPERTY SETTER METHODS */
+
+	/// Set the clock used for the timeouts of waits. Uses the SystemClock by default.
+	pub fn set_clock(&mut self, clock:&'static dyn Clock) {
+		self.clock = ClockRef(clock);
+	}
+
+	/// Set the wait strategy for reads that do not take one, like reads through the embedded-io traits. Uses the default wait strategy by default.
+	pub fn set_wait_strategy(&mut self, wait_strategy:WaitStrategy) {
+		self.wait_strategy = wait_strategy;
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Return the amount of unread samples for this consumer.
+	pub fn len(&self) -> usize {
+		let read_cursor:usize = self.shared.read_cursors[self.cursor_index].load(Ordering::Relaxed);
+		CircularBufferConcurrent::<T, CAPACITY>::used_space(self.shared.write_cursor.load(Ordering::Acquire), read_cursor)
+	}
+
+	/// Wether or not there are 0 unread samples for this consumer.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Wether or not the unread samples of this consumer fill the buffer, so the producer can not write until this consumer reads.
+	pub fn is_full(&self) -> bool {
+		self.len() == CAPACITY - 1
+	}
+
+	/// Return the write sequence, the total amount of samples ever stored to the buffer.
+	pub fn write_sequence(&self) -> usize {
+		self.shared.write_sequence.load(Ordering::Acquire)
+	}
+
+	/// Wether or not the write with the given sequence has become visible to this consumer.
+	pub fn is_sequence_visible(&self, sequence:usize) -> bool {
+		self.write_sequence() >= sequence
+	}
+
+	/// Wether or not the producer has not been closed or dropped yet. Once it has, the samples it wrote can still be taken, after which no more samples will arrive.
+	pub fn is_peer_alive(&self) -> bool {
+		self.shared.producer_alive.load(Ordering::Acquire)
+	}
+
+	/// Return the wait strategy for reads that do not take one.
+	pub fn wait_strategy(&self) -> WaitStrategy {
+		self.wait_strategy
+	}
+
+	/// Wether or not the producer was dropped by a panic, so the stream ended without the producer closing it, possibly in the middle of a message.
+	pub fn is_poisoned(&self) -> bool {
+		self.shared.poisoned.load(Ordering::Acquire)
+	}
+
+	/// Wether or not the stream has ended, as the producer was closed and all samples it wrote are taken.
+	pub fn is_end_of_stream(&self) -> bool {
+		!self.is_peer_alive() && self.is_empty()
+	}
+}
+impl<T:Copy, const CAPACITY:usize> BufferRead<T> for Consumer<T, CAPACITY> {
+	fn len(&self) -> usize {
+		self.len()
+	}
+	fn take_to_buffer(&mut self, output:&mut [T]) -> usize {
+		self.take_to_buffer(output)
+	}
+}
+impl<T:Copy, const CAPACITY:usize> BufferPeek<T> for Consumer<T, CAPACITY> {
+	fn as_slices(&self) -> [&[T]; 2] {
+		self.as_slices()
+	}
+	fn consume(&mut self, amount:usize) -> usize {
+		self.consume(amount)
+	}
+	fn is_full(&self) -> bool {
+		self.is_full()
+	}
+}
+impl<T:Copy, const CAPACITY:usize> Drop for Consumer<T, CAPACITY> {
+	fn drop(&mut self) {
+		self.shared.poison_if_panicking();
+		self.shared.read_cursors[self.cursor_index].store(DROPPED_CURSOR, Ordering::Release);
+		WaiterSlot::wake_all(slice::from_ref(&self.shared.producer_waiter));
+	}
+}
+
+
+
+/// An iterator that takes the samples a consumer had unread when it was created one by one, oldest first, without allocating.
+/// Like the drain of a Vec, all of those samples that were not iterated over are taken when the iterator is dropped.
+pub struct ConsumerDrain<'a, T:Copy, const CAPACITY:usize> {
+	consumer:&'a mut Consumer<T, CAPACITY>,
+	remaining:usize
+}
+impl<T:Copy, const CAPACITY:usize> Iterator for ConsumerDrain<'_, T, CAPACITY> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<T> {
+		if self.remaining == 0 {
+			return None;
+		}
+		let sample:T = self.consumer.peek(1)[0][0];
+		self.consumer.consume(1);
+		self.remaining -= 1;
+		Some(sample)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+impl<T:Copy, const CAPACITY:usize> ExactSizeIterator for ConsumerDrain<'_, T, CAPACITY> {}
+impl<T:Copy, const CAPACITY:usize> Drop for ConsumerDrain<'_, T, CAPACITY> {
+	fn drop(&mut self) {
+		self.consumer.consume(self.remaining);
+	}
+}
@@ -0,0 +1,310 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ BufferRead, CircularBufferConcurrent, Consumer, ConsumerDrain, Disconnected, ManualClock, Producer, WaitStrategy };
+	use std::{ pin::pin, sync::{ Arc, atomic::{ AtomicUsize, Ordering } }, task::{ Context, Poll, Wake, Waker }, thread, time::{ Duration, Instant } };
+
+
+
+	/// A waker that counts how often it was woken, to poll futures by hand.
+	struct CountingWaker(AtomicUsize);
+	impl Wake for CountingWaker {
+		fn wake(self:Arc<CountingWaker>) {
+			self.0.fetch_add(1, Ordering::Relaxed);
+		}
+	}
+
+
+
+	#[test]
+	fn test_keeps_one_slot_free() {
+		let (mut producer, mut consumers) = CircularBufferConcurrent::<i32, 8>::new().split(1);
+		assert_eq!(producer.free_space(), 7);
+		assert_eq!(producer.extend(&[1, 2, 3, 4, 5, 6, 7, 8, 9]), 7);
+		assert_eq!(producer.push(10), 0);
+		assert_eq!(consumers[0].len(), 7);
+
+		assert_eq!(consumers[0].take(3), vec![1, 2, 3]);
+		assert_eq!(producer.extend(&[8, 9, 10, 11]), 3);
+		assert_eq!(consumers[0].take_all(), vec![4, 5, 6, 7, 8, 9, 10]);
+		assert!(consumers[0].is_empty());
+	}
+
+	#[test]
+	fn test_slowest_consumer_limits_producer() {
+		let (mut producer, mut consumers) = CircularBufferConcurrent::<i32, 4>::new().split(2);
+		assert_eq!(producer.extend(&[1, 2, 3]), 3);
+		assert_eq!(consumers[0].take_all(), vec![1, 2, 3]);
+		assert_eq!(producer.free_space(), 0);
+
+		let slow_consumer:Consumer<i32, 4> = consumers.pop().unwrap();
+		assert_eq!(slow_consumer.len(), 3);
+		drop(slow_consumer);
+		assert_eq!(producer.extend(&[4, 5, 6]), 3);
+		assert_eq!(consumers[0].take_all(), vec![4, 5, 6]);
+	}
+
+	#[test]
+	fn test_threaded_producer_consumers() {
+		let (mut producer, consumers) = CircularBufferConcurrent::<u32, 16>::new().split(2);
+		let consumer_threads:Vec<thread::JoinHandle<Vec<u32>>> = consumers.into_iter().map(|mut consumer| thread::spawn(move || {
+			let mut received:Vec<u32> = Vec::new();
+			while received.len() < 1000 {
+				received.extend(consumer.take(5));
+			}
+			received
+		})).collect();
+
+		let input:Vec<u32> = (0..1000).collect();
+		let mut written:usize = 0;
+		while written < input.len() {
+			written += producer.extend(&input[written..(written + 7).min(input.len())]);
+		}
+		for consumer_thread in consumer_threads {
+			assert_eq!(consumer_thread.join().unwrap(), input);
+		}
+	}
+
+	#[test]
+	fn test_blocking_and_timeout_reads() {
+		let (mut producer, mut consumers) = CircularBufferConcurrent::<u32, 8>::new().split(1);
+		let producer_thread:thread::JoinHandle<Producer<u32, 8>> = thread::spawn(move || {
+			let mut value:u32 = 0;
+			while value < 20 {
+				value += producer.push(value) as u32;
+			}
+			producer
+		});
+
+		let mut output:[u32; 20] = [0; 20];
+		assert_eq!(consumers[0].take_blocking(&mut output, WaitStrategy::default()), 20);
+		assert_eq!(output.to_vec(), (0..20).collect::<Vec<u32>>());
+		let mut producer:Producer<u32, 8> = producer_thread.join().unwrap();
+
+		producer.extend(&[1, 2]);
+		let mut output:[u32; 4] = [0; 4];
+		assert_eq!(consumers[0].take_timeout(&mut output, Duration::from_millis(5), WaitStrategy::SpinThenYield(10)), 2);
+		assert_eq!(output[..2], [1, 2]);
+		assert_eq!(BufferRead::len(&consumers[0]), 0);
+	}
+
+	#[test]
+	fn test_dropped_producer_ends_stream() {
+		let (mut producer, mut consumers) = CircularBufferConcurrent::<u32, 8>::new().split(1);
+		assert!(producer.is_peer_alive());
+		assert!(consumers[0].is_peer_alive());
+		let producer_thread:thread::JoinHandle<()> = thread::spawn(move || {
+			producer.extend(&[1, 2, 3]);
+		});
+
+		let mut output:[u32; 10] = [0; 10];
+		assert_eq!(consumers[0].take_blocking(&mut output, WaitStrategy::default()), 3);
+		producer_thread.join().unwrap();
+		assert_eq!(output[..3], [1, 2, 3]);
+		assert!(!consumers[0].is_peer_alive());
+		assert!(!consumers[0].is_poisoned());
+		assert_eq!(consumers[0].take_timeout(&mut output, Duration::from_secs(10), WaitStrategy::default()), 0);
+	}
+
+	#[test]
+	fn test_dropped_consumers_are_not_alive() {
+		let (mut producer, mut consumers) = CircularBufferConcurrent::<u32, 8>::new().split(2);
+		consumers.pop();
+		assert!(producer.is_peer_alive());
+		assert_eq!(producer.try_extend(&[1, 2]), Ok(2));
+		consumers.pop();
+		assert!(!producer.is_peer_alive());
+		assert_eq!(producer.try_extend(&[3]), Err(Disconnected));
+	}
+
+	#[test]
+	fn test_take_more_than_stored() {
+		let (mut producer, mut consumers) = CircularBufferConcurrent::<u32, 8>::new().split(1);
+		producer.extend(&[1, 2]);
+		assert_eq!(consumers[0].take(usize::MAX), vec![1, 2]);
+	}
+
+	#[test]
+	fn test_wait_until_sequence() {
+		let (mut producer, consumers) = CircularBufferConcurrent::<u32, 8>::new().split(1);
+		assert!(consumers[0].is_sequence_visible(0));
+		assert!(!consumers[0].wait_until_sequence_timeout(1, Duration::from_millis(5), WaitStrategy::default()));
+
+		let producer_thread:thread::JoinHandle<usize> = thread::spawn(move || {
+			producer.extend(&[1, 2, 3]);
+			producer.write_sequence()
+		});
+		assert!(consumers[0].wait_until_sequence(3, WaitStrategy::default()));
+		assert_eq!(producer_thread.join().unwrap(), 3);
+		assert_eq!(consumers[0].write_sequence(), 3);
+		assert!(!consumers[0].wait_until_sequence(4, WaitStrategy::default()));
+	}
+
+	#[test]
+	fn test_peek_and_consume() {
+		let (mut producer, mut consumers) = CircularBufferConcurrent::<u32, 8>::new().split(1);
+		producer.extend(&[1, 2, 3, 4, 5, 6]);
+		assert_eq!(consumers[0].take(4), vec![1, 2, 3, 4]);
+		producer.extend(&[7, 8, 9, 10]);
+
+		assert_eq!(consumers[0].as_slices(), [&[5, 6, 7, 8][..], &[9, 10][..]]);
+		assert_eq!(consumers[0].peek(3), [&[5, 6, 7][..], &[][..]]);
+		assert_eq!(consumers[0].consume(5), 5);
+		assert_eq!(consumers[0].as_slices(), [&[10][..], &[][..]]);
+		assert_eq!(consumers[0].consume(10), 1);
+		assert!(consumers[0].is_empty());
+		assert_eq!(producer.free_space(), 7);
+	}
+
+	#[test]
+	fn test_drain() {
+		let (mut producer, mut consumers) = CircularBufferConcurrent::<u32, 8>::new().split(1);
+		producer.extend(&[1, 2, 3, 4]);
+		let mut drain:ConsumerDrain<u32, 8> = consumers[0].drain();
+		assert_eq!(drain.len(), 4);
+		assert_eq!(drain.next(), Some(1));
+		assert_eq!(drain.next(), Some(2));
+
+		// Samples written while draining are left for later reads, while the samples not iterated over are taken on drop.
+		producer.extend(&[5, 6]);
+		drop(drain);
+		assert_eq!(consumers[0].take_all(), vec![5, 6]);
+		assert_eq!(consumers[0].drain().collect::<Vec<u32>>(), Vec::<u32>::new());
+	}
+
+	#[test]
+	fn test_smallest_capacity() {
+		let (mut producer, mut consumers) = CircularBufferConcurrent::<u32, 2>::new().split(1);
+		assert_eq!(producer.free_space(), 1);
+		assert_eq!(producer.extend(&[1, 2]), 1);
+		assert_eq!(consumers[0].take_all(), vec![1]);
+		assert_eq!(producer.push(3), 1);
+		assert_eq!(consumers[0].as_slices(), [&[3][..], &[][..]]);
+	}
+
+	#[test]
+	fn test_parked_threads_are_unparked() {
+		let wait_strategy:WaitStrategy = WaitStrategy::SpinThenPark(0, Duration::from_secs(60));
+		let (mut producer, mut consumers) = CircularBufferConcurrent::<u32, 4>::new().split(1);
+		let start:Instant = Instant::now();
+		let producer_thread:thread::JoinHandle<usize> = thread::spawn(move || producer.extend_blocking(&[1, 2, 3, 4, 5, 6, 7, 8], wait_strategy));
+
+		let mut output:[u32; 8] = [0; 8];
+		assert_eq!(consumers[0].take_blocking(&mut output, wait_strategy), 8);
+		assert_eq!(producer_thread.join().unwrap(), 8);
+		assert_eq!(output, [1, 2, 3, 4, 5, 6, 7, 8]);
+		assert!(start.elapsed() < Duration::from_secs(30));
+	}
+
+	#[test]
+	fn test_async_take_and_extend() {
+		let (mut producer, mut consumers) = CircularBufferConcurrent::<u32, 4>::new().split(1);
+		let wake_count:Arc<CountingWaker> = Arc::new(CountingWaker(AtomicUsize::new(0)));
+		let waker:Waker = Waker::from(Arc::clone(&wake_count));
+		let mut context:Context = Context::from_waker(&waker);
+
+		let mut output:[u32; 2] = [0; 2];
+		{
+			let mut take = pin!(consumers[0].take_async(&mut output));
+			assert_eq!(take.as_mut().poll(&mut context), Poll::Pending);
+			producer.push(1);
+			assert_eq!(wake_count.0.load(Ordering::Relaxed), 1);
+			assert_eq!(take.as_mut().poll(&mut context), Poll::Pending);
+			producer.push(2);
+			assert_eq!(take.as_mut().poll(&mut context), Poll::Ready(2));
+		}
+		assert_eq!(output, [1, 2]);
+
+		{
+			let mut extend = pin!(producer.extend_async(&[3, 4, 5, 6]));
+			assert_eq!(extend.as_mut().poll(&mut context), Poll::Pending);
+			assert_eq!(consumers[0].take(2), vec![3, 4]);
+			assert_eq!(wake_count.0.load(Ordering::Relaxed), 3);
+			assert_eq!(extend.as_mut().poll(&mut context), Poll::Ready(4));
+		}
+		assert_eq!(consumers[0].take_all(), vec![5, 6]);
+
+		// Once the waiting future is dropped, its waker is no longer woken.
+		{
+			let mut take = pin!(consumers[0].take_async(&mut output));
+			assert_eq!(take.as_mut().poll(&mut context), Poll::Pending);
+		}
+		producer.push(7);
+		assert_eq!(wake_count.0.load(Ordering::Relaxed), 3);
+	}
+
+	#[test]
+	fn test_timeouts_use_consumer_clock() {
+		static CLOCK:ManualClock = ManualClock::new();
+		let (_producer, mut consumers) = CircularBufferConcurrent::<u32, 4>::new().split(1);
+		consumers[0].set_clock(&CLOCK);
+		let start:Instant = Instant::now();
+		let clock_thread:thread::JoinHandle<()> = thread::spawn(|| {
+			thread::sleep(Duration::from_millis(20));
+			CLOCK.advance(Duration::from_secs(120));
+		});
+
+		let mut output:[u32; 1] = [0; 1];
+		assert_eq!(consumers[0].take_timeout(&mut output, Duration::from_secs(60), WaitStrategy::SpinThenYield(10)), 0);
+		clock_thread.join().unwrap();
+		assert!(start.elapsed() < Duration::from_secs(30));
+	}
+
+	#[test]
+	fn test_wait_until_sequence_async() {
+		let (mut producer, consumers) = CircularBufferConcurrent::<u32, 8>::new().split(1);
+		let waker:Waker = Waker::from(Arc::new(CountingWaker(AtomicUsize::new(0))));
+		let mut context:Context = Context::from_waker(&waker);
+
+		let mut wait = pin!(consumers[0].wait_until_sequence_async(3));
+		assert_eq!(wait.as_mut().poll(&mut context), Poll::Pending);
+		producer.extend(&[1, 2]);
+		assert_eq!(wait.as_mut().poll(&mut context), Poll::Pending);
+		producer.push(3);
+		assert_eq!(wait.as_mut().poll(&mut context), Poll::Ready(true));
+
+		let mut wait = pin!(consumers[0].wait_until_sequence_async(4));
+		assert_eq!(wait.as_mut().poll(&mut context), Poll::Pending);
+		drop(producer);
+		assert_eq!(wait.as_mut().poll(&mut context), Poll::Ready(false));
+	}
+
+	#[test]
+	fn test_close_ends_stream() {
+		let (mut producer, mut consumers) = CircularBufferConcurrent::<u32, 8>::new().split(1);
+		let mut consumer:Consumer<u32, 8> = consumers.pop().unwrap();
+		let consumer_thread:thread::JoinHandle<(usize, Consumer<u32, 8>)> = thread::spawn(move || {
+			let mut output:[u32; 8] = [0; 8];
+			(consumer.take_blocking(&mut output, WaitStrategy::SpinThenPark(0, Duration::from_secs(60))), consumer)
+		});
+		producer.extend(&[1, 2, 3]);
+		producer.close();
+		assert!(producer.is_closed());
+		assert_eq!(producer.extend(&[4]), 0);
+		assert_eq!(producer.extend_blocking(&[4], WaitStrategy::BusySpin), 0);
+
+		let (taken, mut consumer) = consumer_thread.join().unwrap();
+		assert_eq!(taken, 3);
+		assert!(consumer.is_end_of_stream());
+		let mut output:[u32; 2] = [0; 2];
+		assert_eq!(consumer.take_blocking(&mut output, WaitStrategy::BusySpin), 0);
+		assert!(!consumer.wait_until_sequence(4, WaitStrategy::BusySpin));
+
+		let waker:Waker = Waker::from(Arc::new(CountingWaker(AtomicUsize::new(0))));
+		assert_eq!(pin!(consumer.take_async(&mut output)).poll(&mut Context::from_waker(&waker)), Poll::Ready(0));
+	}
+
+	#[test]
+	fn test_panicking_producer_poisons_buffer() {
+		let (mut producer, mut consumers) = CircularBufferConcurrent::<u32, 8>::new().split(1);
+		let producer_thread:thread::JoinHandle<()> = thread::spawn(move || {
+			producer.extend(&[1, 2]);
+			panic!("Producer failed mid-message.");
+		});
+
+		let mut output:[u32; 4] = [0; 4];
+		assert_eq!(consumers[0].take_blocking(&mut output, WaitStrategy::SpinThenPark(0, Duration::from_secs(60))), 2);
+		assert!(producer_thread.join().is_err());
+		assert!(consumers[0].is_poisoned());
+		assert!(consumers[0].is_end_of_stream());
+	}
+}
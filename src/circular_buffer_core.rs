@@ -0,0 +1,805 @@
+use crate::{ BufferWrite, bulk_copy::{ copy_samples, copy_samples_to_uninit } };
+#[cfg(feature = "cursor-timeout")]
+use crate::clock::ClockRef;
+#[cfg(feature = "statistics")]
+use crate::{ BufferStatistics, CursorStatistics };
+#[cfg(feature = "registry")]
+use crate::registry::Registration;
+#[cfg(feature = "dsp")]
+use crate::{ WindowFunction, WindowSample };
+use std::{ io::Write, marker::PhantomData, mem::MaybeUninit };
+#[cfg(feature = "cursor-timeout")]
+use std::time::Duration;
+
+
+
+/// The state of a single read cursor.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct CursorState {
+	pub(crate) position:usize,
+	#[cfg(feature = "cursor-timeout")]
+	pub(crate) read_time:Option<Duration>,
+	#[cfg(feature = "cursor-timeout")]
+	pub(crate) expired:bool,
+	#[cfg(feature = "statistics")]
+	pub(crate) statistics:CursorStatistics
+}
+impl CursorState {
+
+	/// Create a new cursor state at the given position.
+	pub const fn new(position:usize) -> CursorState {
+		CursorState {
+			position,
+			#[cfg(feature = "cursor-timeout")]
+			read_time: None,
+			#[cfg(feature = "cursor-timeout")]
+			expired: false,
+			#[cfg(feature = "statistics")]
+			statistics: CursorStatistics::new()
+		}
+	}
+}
+
+
+
+/// Decides how many read cursors a CircularBufferCore has, where their state is kept and how samples are copied.
+pub trait CursorPolicy<T> {
+
+	/// Wether or not the activity of cursors is recorded, which reads the clock on every read while a cursor timeout is set.
+	const RECORDS_ACTIVITY:bool;
+
+	/// Return the state of all active cursors.
+	fn cursors(&self) -> &[CursorState];
+
+	/// Return the mutable state of all active cursors.
+	fn cursors_mut(&mut self) -> &mut [CursorState];
+
+	/// Copy samples from the source to the destination. Both slices need to be the same length.
+	fn copy_samples(destination:&mut [T], source:&[T]);
+
+	/// Copy samples from the source to the uninitialized destination. Both slices need to be the same length.
+	fn copy_to_uninit(destination:&mut [MaybeUninit<T>], source:&[T]);
+
+	/// Return the amount of heap allocated bytes used to keep the state of the cursors.
+	fn memory_usage(&self) -> usize {
+		0
+	}
+}
+
+/// A cursor policy that allows creating multiple read cursors.
+pub trait MultiCursorPolicy {
+
+	/// Add a cursor with the given state. Returns the index of the cursor, or None if the max amount of cursors is reached.
+	fn add_cursor(&mut self, state:CursorState) -> Option<usize>;
+}
+
+
+
+/// Decides how a SingleReader copies samples in and out of the buffer.
+pub trait SampleCopy<T> {
+
+	/// Copy samples from the source to the destination. Both slices need to be the same length.
+	fn copy_samples(destination:&mut [T], source:&[T]);
+
+	/// Copy samples from the source to the uninitialized destination. Both slices need to be the same length.
+	fn copy_to_uninit(destination:&mut [MaybeUninit<T>], source:&[T]);
+}
+
+/// Copies samples by cloning them, which allows any Clone sample.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub struct CloneSamples;
+impl<T:Clone> SampleCopy<T> for CloneSamples {
+	fn copy_samples(destination:&mut [T], source:&[T]) {
+		destination.clone_from_slice(source);
+	}
+	fn copy_to_uninit(destination:&mut [MaybeUninit<T>], source:&[T]) {
+		destination.write_clone_of_slice(source);
+	}
+}
+
+/// Copies Copy samples in bulk, which allows using the bulk copy features.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub struct BulkCopySamples;
+impl<T:Copy> SampleCopy<T> for BulkCopySamples {
+	fn copy_samples(destination:&mut [T], source:&[T]) {
+		copy_samples(destination, source);
+	}
+	fn copy_to_uninit(destination:&mut [MaybeUninit<T>], source:&[T]) {
+		copy_samples_to_uninit(destination, source);
+	}
+}
+
+
+
+/// A single implicit read cursor, as used by CircularBuffer, CircularBufferDyn and CircularBufferDynBulk.
+/// The sample copy decides the requirements of the samples. CircularBuffer and CircularBufferDynBulk copy Copy samples in bulk, CircularBufferDyn clones samples.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct SingleReader<M = CloneSamples>(CursorState, PhantomData<M>);
+impl<M> SingleReader<M> {
+
+	/// Create a new single reader policy.
+	pub const fn new() -> SingleReader<M> {
+		SingleReader(CursorState::new(0), PhantomData)
+	}
+}
+impl<M> Default for SingleReader<M> {
+	fn default() -> SingleReader<M> {
+		SingleReader::new()
+	}
+}
+impl<T, M:SampleCopy<T>> CursorPolicy<T> for SingleReader<M> {
+	const RECORDS_ACTIVITY:bool = false;
+	fn cursors(&self) -> &[CursorState] {
+		std::slice::from_ref(&self.0)
+	}
+	fn cursors_mut(&mut self) -> &mut [CursorState] {
+		std::slice::from_mut(&mut self.0)
+	}
+	fn copy_samples(destination:&mut [T], source:&[T]) {
+		M::copy_samples(destination, source);
+	}
+	fn copy_to_uninit(destination:&mut [MaybeUninit<T>], source:&[T]) {
+		M::copy_to_uninit(destination, source);
+	}
+}
+
+
+
+/// Up to MAX_READ_CURSOR_COUNT read cursors kept in an array, as used by CircularBufferMultiRead. Samples need to be Copy, which allows using the bulk copy features.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct FixedReaders<const MAX_READ_CURSOR_COUNT:usize> {
+	cursors:[CursorState; MAX_READ_CURSOR_COUNT],
+	cursor_count:usize
+}
+impl<const MAX_READ_CURSOR_COUNT:usize> FixedReaders<MAX_READ_CURSOR_COUNT> {
+
+	/// Create a new fixed readers policy without any cursors.
+	pub const fn new() -> FixedReaders<MAX_READ_CURSOR_COUNT> {
+		FixedReaders {
+			cursors: [CursorState::new(0); MAX_READ_CURSOR_COUNT],
+			cursor_count: 0
+		}
+	}
+}
+impl<const MAX_READ_CURSOR_COUNT:usize> Default for FixedReaders<MAX_READ_CURSOR_COUNT> {
+	fn default() -> FixedReaders<MAX_READ_CURSOR_COUNT> {
+		FixedReaders::new()
+	}
+}
+impl<T:Copy, const MAX_READ_CURSOR_COUNT:usize> CursorPolicy<T> for FixedReaders<MAX_READ_CURSOR_COUNT> {
+	const RECORDS_ACTIVITY:bool = true;
+	fn cursors(&self) -> &[CursorState] {
+		&self.cursors[..self.cursor_count]
+	}
+	fn cursors_mut(&mut self) -> &mut [CursorState] {
+		&mut self.cursors[..self.cursor_count]
+	}
+	fn copy_samples(destination:&mut [T], source:&[T]) {
+		copy_samples(destination, source);
+	}
+	fn copy_to_uninit(destination:&mut [MaybeUninit<T>], source:&[T]) {
+		copy_samples_to_uninit(destination, source);
+	}
+}
+impl<const MAX_READ_CURSOR_COUNT:usize> MultiCursorPolicy for FixedReaders<MAX_READ_CURSOR_COUNT> {
+	fn add_cursor(&mut self, state:CursorState) -> Option<usize> {
+		if self.cursor_count == MAX_READ_CURSOR_COUNT {
+			return None;
+		}
+		self.cursors[self.cursor_count] = state;
+		self.cursor_count += 1;
+		Some(self.cursor_count - 1)
+	}
+}
+
+
+
+/// Any amount of read cursors kept in a Vec, as used by CircularBufferMultiReadDyn. Samples need to be Copy, which allows using the bulk copy features.
+#[derive(PartialEq, Eq, Clone, Default)]
+pub struct DynamicReaders(Vec<CursorState>);
+impl DynamicReaders {
+
+	/// Create a new dynamic readers policy without any cursors.
+	pub const fn new() -> DynamicReaders {
+		DynamicReaders(Vec::new())
+	}
+}
+impl<T:Copy> CursorPolicy<T> for DynamicReaders {
+	const RECORDS_ACTIVITY:bool = true;
+	fn cursors(&self) -> &[CursorState] {
+		&self.0
+	}
+	fn cursors_mut(&mut self) -> &mut [CursorState] {
+		&mut self.0
+	}
+	fn copy_samples(destination:&mut [T], source:&[T]) {
+		copy_samples(destination, source);
+	}
+	fn copy_to_uninit(destination:&mut [MaybeUninit<T>], source:&[T]) {
+		copy_samples_to_uninit(destination, source);
+	}
+	fn memory_usage(&self) -> usize {
+		self.0.capacity() * size_of::<CursorState>()
+	}
+}
+impl MultiCursorPolicy for DynamicReaders {
+	fn add_cursor(&mut self, state:CursorState) -> Option<usize> {
+		self.0.push(state);
+		Some(self.0.len() - 1)
+	}
+}
+
+
+
+/// Storage of which the size is chosen at runtime, like a Vec or an AlignedStorage, as used by the dyn buffers.
+/// Wrapping the storage keeps the constructors of the dyn buffers apart from those of the array based buffers.
+#[derive(PartialEq, Eq, Clone, Copy, Default)]
+pub struct DynStorage<S>(S);
+impl<S> DynStorage<S> {
+
+	/// Create a new dyn storage wrapping the given storage.
+	pub const fn new(storage:S) -> DynStorage<S> {
+		DynStorage(storage)
+	}
+
+	/// Return the wrapped storage.
+	pub fn into_inner(self) -> S {
+		self.0
+	}
+}
+impl<T, S:AsRef<[T]>> AsRef<[T]> for DynStorage<S> {
+	fn as_ref(&self) -> &[T] {
+		self.0.as_ref()
+	}
+}
+impl<T, S:AsMut<[T]>> AsMut<[T]> for DynStorage<S> {
+	fn as_mut(&mut self) -> &mut [T] {
+		self.0.as_mut()
+	}
+}
+
+
+
+/// The circular buffer all buffer variants are built on, configured by the storage of the samples and the policy of the read cursors.
+/// CircularBuffer, CircularBufferDyn, CircularBufferMultiRead and CircularBufferMultiReadDyn are combinations of array or dyn storage with a single or multi cursor policy, but any combination can be used.
+/// Buffers compare equal when their storage, write cursor and read cursor positions are equal. Statistics, registration and other bookkeeping are not compared.
+/// Buffers with Copy storage and cursors are Copy, except with the 'registry' feature, as a registration belongs to a single buffer and is released on drop.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "registry"), derive(Copy))]
+pub struct CircularBufferCore<T, S, C> {
+	pub(crate) buffer:S,
+	pub(crate) capacity:usize, // Same as buffer.len(), but dynamically fetching is not useful as the buffer length always stays the same.
+	pub(crate) cursors:C,
+	pub(crate) write_cursor:usize,
+	pub(crate) frozen:bool,
+	pub(crate) frozen_rejected_count:usize,
+	pub(crate) sample_type:PhantomData<T>,
+	pub(crate) write_sequence:usize,
+	#[cfg(feature = "statistics")]
+	pub(crate) statistics:BufferStatistics,
+	#[cfg(feature = "cursor-timeout")]
+	pub(crate) clock:ClockRef,
+	#[cfg(feature = "cursor-timeout")]
+	pub(crate) cursor_timeout:Option<Duration>,
+	#[cfg(feature = "registry")]
+	pub(crate) registration:Registration
+}
+impl<T, S, C> CircularBufferCore<T, S, C> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new circular-buffer from the given storage and cursors. The capacity needs to be the length of the storage.
+	pub(crate) const fn from_parts(buffer:S, capacity:usize, cursors:C) -> CircularBufferCore<T, S, C> {
+		CircularBufferCore {
+			buffer,
+			capacity,
+			cursors,
+			write_cursor: 0,
+			frozen: false,
+			frozen_rejected_count: 0,
+			sample_type: PhantomData,
+			write_sequence: 0,
+			#[cfg(feature = "statistics")]
+			statistics: BufferStatistics::new(),
+			#[cfg(feature = "cursor-timeout")]
+			clock: ClockRef::SYSTEM,
+			#[cfg(feature = "cursor-timeout")]
+			cursor_timeout: None,
+			#[cfg(feature = "registry")]
+			registration: Registration::NONE
+		}
+	}
+}
+impl<T:Default + Copy, const CAPACITY:usize, C:CursorPolicy<T> + Default> CircularBufferCore<T, [T; CAPACITY], C> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new circular-buffer.
+	pub fn new() -> CircularBufferCore<T, [T; CAPACITY], C> {
+		CircularBufferCore::from_parts([T::default(); CAPACITY], CAPACITY, C::default())
+	}
+}
+impl<T:Default + Copy, const CAPACITY:usize, C:CursorPolicy<T> + Default> Default for CircularBufferCore<T, [T; CAPACITY], C> {
+	fn default() -> CircularBufferCore<T, [T; CAPACITY], C> {
+		CircularBufferCore::<T, [T; CAPACITY], C>::new()
+	}
+}
+impl<T:PartialEq, S:AsRef<[T]>, C:CursorPolicy<T>> PartialEq for CircularBufferCore<T, S, C> {
+	fn eq(&self, other:&CircularBufferCore<T, S, C>) -> bool {
+		let positions_equal:bool = self.cursors.cursors().iter().map(|cursor| cursor.position).eq(other.cursors.cursors().iter().map(|cursor| cursor.position));
+		positions_equal && self.write_cursor == other.write_cursor && self.buffer.as_ref() == other.buffer.as_ref()
+	}
+}
+impl<T:Eq, S:AsRef<[T]>, C:CursorPolicy<T>> Eq for CircularBufferCore<T, S, C> {}
+impl<T, S:AsRef<[T]> + AsMut<[T]>, C:CursorPolicy<T> + Default> CircularBufferCore<T, DynStorage<S>, C> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new circular-buffer using the given storage. The capacity of the buffer will be the length of the storage.
+	pub fn from_storage(storage:S) -> CircularBufferCore<T, DynStorage<S>, C> {
+		let capacity:usize = storage.as_ref().len();
+		CircularBufferCore::from_parts(DynStorage(storage), capacity, C::default())
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Return the total amount of bytes held by the buffer, including its storage and the state of all cursors.
+	pub fn memory_usage(&self) -> usize {
+		size_of::<CircularBufferCore<T, DynStorage<S>, C>>() + self.capacity * size_of::<T>() + self.cursors.memory_usage()
+	}
+}
+impl<T:Clone, S:AsRef<[T]> + AsMut<[T]>, C:CursorPolicy<T>> CircularBufferCore<T, S, C> {
+
+	/* BUFFER WRITING METHODS */
+
+	/// Freeze the buffer. While frozen, all written samples are rejected, while reading continues as normal.
+	pub fn freeze(&mut self) {
+		self.frozen = true;
+	}
+
+	/// Unfreeze the buffer, accepting written samples again.
+	pub fn unfreeze(&mut self) {
+		self.frozen = false;
+	}
+
+	/// Add a single sample to the buffer. Returns the amount of samples stored to the buffer.
+	pub fn push(&mut self, input:T) -> usize {
+		self.extend(&[input])
+	}
+
+	/// Add a single sample to the buffer, displacing the oldest sample if the buffer is full for any cursor. The cursors that had not read the displaced sample skip it. Returns the displaced sample, if any.
+	/// While frozen, the sample is rejected and nothing is displaced.
+	pub fn push_overwrite(&mut self, input:T) -> Option<T> {
+		#[cfg(feature = "cursor-timeout")]
+		self.expire_inactive_cursors();
+		let mut displaced:Option<T> = None;
+		let largest_used_space:usize = self.largest_len();
+		if !self.frozen && largest_used_space != 0 && largest_used_space == self.capacity - 1 {
+			let oldest_index:usize = (self.write_cursor + 1) % self.capacity;
+			displaced = Some(self.buffer.as_ref()[oldest_index].clone());
+			for cursor_index in 0..self.cursors.cursors().len() {
+				if self.cursor_len(cursor_index) == largest_used_space {
+					let cursor:&mut CursorState = &mut self.cursors.cursors_mut()[cursor_index];
+					cursor.position = (oldest_index + 1) % self.capacity;
+					#[cfg(feature = "statistics")]
+					cursor.statistics.record_skipped(1);
+				}
+			}
+		}
+		self.extend(&[input]);
+		displaced
+	}
+
+	/// Add an array of samples to the buffer only if all of them fit. Returns wether or not the samples were stored.
+	pub fn push_array<const N:usize>(&mut self, input:[T; N]) -> bool {
+		#[cfg(feature = "cursor-timeout")]
+		self.expire_inactive_cursors();
+		self.reject_unless_fits(N) && self.extend(&input) == N
+	}
+
+	/// Add a list of samples to the buffer. Returns the amount of samples stored to the buffer.
+	pub fn extend(&mut self, input:&[T]) -> usize {
+
+		// Reject all input while frozen.
+		if self.frozen {
+			self.frozen_rejected_count += input.len();
+			return 0;
+		}
+
+		// Store input and keep track of statistics.
+		#[cfg(feature = "cursor-timeout")]
+		self.expire_inactive_cursors();
+		#[cfg(feature = "statistics")]
+		let occupancy:usize = self.largest_len();
+		let written:usize = self.store(input);
+		#[cfg(feature = "statistics")]
+		self.record_write(occupancy, input.len(), written);
+		written
+	}
+
+	/// Check if the given amount of samples fits in the buffer, recording a failed write if it does not. Always passes while frozen, so the write is counted as rejected.
+	pub(crate) fn reject_unless_fits(&mut self, amount:usize) -> bool {
+		let occupancy:usize = self.largest_len();
+		if !self.frozen && amount > self.capacity.saturating_sub(occupancy + 1) {
+			#[cfg(feature = "statistics")]
+			self.record_write(occupancy, amount, 0);
+			return false;
+		}
+		true
+	}
+
+	/// Store a list of samples to the buffer. Returns the amount of samples stored to the buffer.
+	fn store(&mut self, input:&[T]) -> usize {
+
+		// Find out how much free space is left before wrap.
+		let largest_used_space:usize = self.largest_len();
+		let available_space:usize = self.capacity - largest_used_space;
+		let required_space:usize = input.len();
+
+		// If input is too large, only write beginning. Always keep one "empty" slot. This makes sure both cursors with the same value always means the buffer is empty, rather than full.
+		if required_space >= available_space {
+			return self.store(&input[..available_space - 1]);
+		}
+
+		// If not enough space before wrap, return or split into two modifications.
+		let available_space_before_wrap:usize = self.capacity - self.write_cursor;
+		if available_space_before_wrap < required_space {
+			return self.store(&input[..available_space_before_wrap]) + self.store(&input[available_space_before_wrap..required_space]);
+		}
+
+		// If enough space before wrap, write to buffer.
+		C::copy_samples(&mut self.buffer.as_mut()[self.write_cursor..self.write_cursor + required_space], input);
+		self.write_cursor = (self.write_cursor + required_space) % self.capacity;
+		self.write_sequence += required_space;
+		required_space
+	}
+
+
+
+	/* CURSOR METHODS */
+
+	/// Keep track of the last activity of a cursor. The clock is only read while a cursor timeout is set.
+	#[cfg(feature = "cursor-timeout")]
+	pub(crate) fn record_read(&mut self, cursor_index:usize) {
+		if !C::RECORDS_ACTIVITY || self.cursor_timeout.is_none() {
+			return;
+		}
+		self.cursors.cursors_mut()[cursor_index].read_time = Some(self.clock.0.now());
+	}
+
+	/// Keep track of the last activity of a cursor. Without the 'cursor-timeout' feature, there is nothing to keep track of.
+	#[cfg(not(feature = "cursor-timeout"))]
+	pub(crate) fn record_read(&mut self, _cursor_index:usize) {}
+
+	/// Expire all cursors that have not been active within the cursor timeout.
+	#[cfg(feature = "cursor-timeout")]
+	fn expire_inactive_cursors(&mut self) {
+		let Some(timeout) = self.cursor_timeout else {
+			return;
+		};
+		let now:Duration = self.clock.0.now();
+		for cursor in self.cursors.cursors_mut() {
+			if let Some(read_time) = cursor.read_time && now.saturating_sub(read_time) > timeout {
+				cursor.expired = true;
+			}
+		}
+	}
+
+	/// Move a cursor forward by the given amount of samples, counting them as taken.
+	pub(crate) fn advance_cursor(&mut self, cursor_index:usize, amount:usize) {
+		let capacity:usize = self.capacity;
+		let cursor:&mut CursorState = &mut self.cursors.cursors_mut()[cursor_index];
+		cursor.position = (cursor.position + amount) % capacity;
+		#[cfg(feature = "statistics")]
+		cursor.statistics.record_taken(amount);
+	}
+
+	/// Return the amount of unread samples stored for a specific cursor.
+	pub(crate) fn cursor_len(&self, cursor_index:usize) -> usize {
+		let cursor:&CursorState = &self.cursors.cursors()[cursor_index];
+		#[cfg(feature = "cursor-timeout")]
+		if cursor.expired {
+			return 0;
+		}
+		if self.write_cursor >= cursor.position {
+			self.write_cursor - cursor.position
+		} else {
+			self.capacity - (cursor.position - self.write_cursor)
+		}.min(self.capacity)
+	}
+
+	/// Return the amount of unread samples stored for the cursor that is furthest behind.
+	pub(crate) fn largest_len(&self) -> usize {
+		(0..self.cursors.cursors().len()).map(|cursor_index| self.cursor_len(cursor_index)).max().unwrap_or_default()
+	}
+
+	/// Get all unread samples for a specific cursor as two slices. The second slice holds the samples after the wrap.
+	pub(crate) fn cursor_unread_slices(&self, cursor_index:usize) -> [&[T]; 2] {
+		let read_cursor:usize = self.cursors.cursors()[cursor_index].position;
+		let used_space:usize = self.cursor_len(cursor_index);
+		let straight_space:usize = used_space.min(self.capacity - read_cursor);
+		[&self.buffer.as_ref()[read_cursor..read_cursor + straight_space], &self.buffer.as_ref()[..used_space - straight_space]]
+	}
+
+	/// Get at most the given amount of unread samples for a specific cursor as two slices, without taking them. The second slice holds the samples after the wrap.
+	pub(crate) fn cursor_peek(&self, amount:usize, cursor_index:usize) -> [&[T]; 2] {
+		let [first, second] = self.cursor_unread_slices(cursor_index);
+		let first_amount:usize = amount.min(first.len());
+		[&first[..first_amount], &second[..(amount - first_amount).min(second.len())]]
+	}
+
+	/// Create an iterator that takes all unread samples for a specific cursor one by one.
+	pub(crate) fn cursor_drain(&mut self, cursor_index:usize) -> Drain<'_, T, S, C> {
+		self.record_read(cursor_index);
+		Drain { buffer: self, cursor_index }
+	}
+
+	/// Get all data that is written in the buffer, starting at the position of a specific cursor.
+	pub(crate) fn cursor_raw_data(&self, cursor_index:usize) -> Vec<T> {
+		let mut output:Vec<T> = self.buffer.as_ref().to_vec();
+		output.rotate_left(self.cursors.cursors()[cursor_index].position);
+		output
+	}
+
+	/// Drop the oldest unread samples for a specific cursor while the predicate returns true for them. Returns the amount of dropped samples, which count as skipped.
+	pub(crate) fn cursor_evict_while<F:FnMut(&T) -> bool>(&mut self, mut predicate:F, cursor_index:usize) -> usize {
+		self.record_read(cursor_index);
+		let [straight_samples, wrapped_samples] = self.cursor_unread_slices(cursor_index);
+		let evicted_amount:usize = straight_samples.iter().chain(wrapped_samples).take_while(|sample| predicate(sample)).count();
+		let capacity:usize = self.capacity;
+		let cursor:&mut CursorState = &mut self.cursors.cursors_mut()[cursor_index];
+		cursor.position = (cursor.position + evicted_amount) % capacity;
+		#[cfg(feature = "statistics")]
+		cursor.statistics.record_skipped(evicted_amount);
+		evicted_amount
+	}
+
+	/// Mark an amount of unread samples as read for a specific cursor without copying them. Returns the amount of samples consumed.
+	pub(crate) fn cursor_consume(&mut self, amount:usize, cursor_index:usize) -> usize {
+		self.record_read(cursor_index);
+		let consumed:usize = amount.min(self.cursor_len(cursor_index));
+		self.advance_cursor(cursor_index, consumed);
+		consumed
+	}
+
+	/// Skip a cursor to the end of data, ignoring all current data.
+	pub(crate) fn cursor_skip_current_data(&mut self, cursor_index:usize) {
+		#[cfg(feature = "statistics")]
+		let skipped_amount:usize = self.cursor_len(cursor_index);
+		let write_cursor:usize = self.write_cursor;
+		let cursor:&mut CursorState = &mut self.cursors.cursors_mut()[cursor_index];
+		#[cfg(feature = "statistics")]
+		cursor.statistics.record_skipped(skipped_amount);
+		cursor.position = write_cursor;
+		self.record_read(cursor_index);
+	}
+
+	/// Take one sample from the buffer for a specific cursor. Returns the default value if there are no unread samples.
+	pub(crate) fn cursor_take_one(&mut self, cursor_index:usize) -> T where T:Default {
+		self.cursor_take(1, cursor_index).pop().unwrap_or_default()
+	}
+
+	/// Take an amount of samples from the buffer for a specific cursor.
+	pub(crate) fn cursor_take(&mut self, amount:usize, cursor_index:usize) -> Vec<T> {
+		let amount:usize = amount.min(self.cursor_len(cursor_index));
+		let mut output:Vec<T> = Vec::with_capacity(amount);
+		let taken:usize = self.cursor_take_to_uninit(&mut output.spare_capacity_mut()[..amount], cursor_index);
+		unsafe { output.set_len(taken); }
+		output
+	}
+
+	/// Take at most max samples from the buffer for a specific cursor, but only once at least min samples are stored. Returns None and takes nothing if less than min samples are stored. A max below min is raised to min.
+	pub(crate) fn cursor_take_at_least(&mut self, min:usize, max:usize, cursor_index:usize) -> Option<Vec<T>> {
+		if self.cursor_len(cursor_index) < min {
+			self.record_read(cursor_index);
+			return None;
+		}
+		Some(self.cursor_take(max.max(min), cursor_index))
+	}
+
+	/// Take exactly N samples from the buffer into an array for a specific cursor. Returns None and takes nothing if less than N samples are stored.
+	pub(crate) fn cursor_take_array<const N:usize>(&mut self, cursor_index:usize) -> Option<[T; N]> {
+		self.record_read(cursor_index);
+		if self.cursor_len(cursor_index) < N {
+			return None;
+		}
+		let [first, second] = self.cursor_unread_slices(cursor_index);
+		let output:[T; N] = std::array::from_fn(|index| if index < first.len() { first[index].clone() } else { second[index - first.len()].clone() });
+		self.advance_cursor(cursor_index, N);
+		Some(output)
+	}
+
+	/// Take an amount of samples from the buffer for a specific cursor. Writes the data to the given output. Returns the amount of data taken from the buffer.
+	pub(crate) fn cursor_take_to_buffer(&mut self, output:&mut [T], cursor_index:usize) -> usize {
+		self.record_read(cursor_index);
+		let [first, second] = self.cursor_unread_slices(cursor_index);
+		let straight_space:usize = output.len().min(first.len());
+		let wrapped_space:usize = (output.len() - straight_space).min(second.len());
+		C::copy_samples(&mut output[..straight_space], &first[..straight_space]);
+		C::copy_samples(&mut output[straight_space..straight_space + wrapped_space], &second[..wrapped_space]);
+		self.advance_cursor(cursor_index, straight_space + wrapped_space);
+		straight_space + wrapped_space
+	}
+
+	/// Take an amount of samples from the buffer for a specific cursor. Writes the data to the given uninitialized output. Returns the amount of data taken from the buffer, which is the amount of samples at the start of the output that are initialized.
+	pub(crate) fn cursor_take_to_uninit(&mut self, output:&mut [MaybeUninit<T>], cursor_index:usize) -> usize {
+		self.record_read(cursor_index);
+		let [first, second] = self.cursor_unread_slices(cursor_index);
+		let straight_space:usize = output.len().min(first.len());
+		let wrapped_space:usize = (output.len() - straight_space).min(second.len());
+		C::copy_to_uninit(&mut output[..straight_space], &first[..straight_space]);
+		C::copy_to_uninit(&mut output[straight_space..straight_space + wrapped_space], &second[..wrapped_space]);
+		self.advance_cursor(cursor_index, straight_space + wrapped_space);
+		straight_space + wrapped_space
+	}
+
+	/// Take a window of samples from the buffer for a specific cursor, while only consuming the hop size. Writes the window to the start of the output.
+	/// Returns false and takes nothing if less than the window or hop size is stored, or if the output is smaller than the window.
+	pub(crate) fn cursor_take_windowed(&mut self, window:usize, hop:usize, output:&mut [T], cursor_index:usize) -> bool {
+		self.record_read(cursor_index);
+		if self.cursor_len(cursor_index) < window.max(hop) || output.len() < window {
+			return false;
+		}
+		let window_start:usize = self.cursors.cursors()[cursor_index].position;
+		let straight_space:usize = window.min(self.capacity - window_start);
+		C::copy_samples(&mut output[..straight_space], &self.buffer.as_ref()[window_start..window_start + straight_space]);
+		C::copy_samples(&mut output[straight_space..window], &self.buffer.as_ref()[..window - straight_space]);
+		self.advance_cursor(cursor_index, hop);
+		true
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Return the most recently written sample, even if it has already been read. Does not affect reading. Returns None if no samples were ever written.
+	pub fn last_written(&self) -> Option<&T> {
+		if self.write_sequence == 0 {
+			return None;
+		}
+		Some(&self.buffer.as_ref()[(self.write_cursor + self.capacity - 1) % self.capacity])
+	}
+
+	/// Return the write sequence, the total amount of samples ever stored to the buffer. A sample is visible to readers once the write sequence has passed its position.
+	pub fn write_sequence(&self) -> usize {
+		self.write_sequence
+	}
+
+	/// Wether or not the write with the given sequence has become visible to readers.
+	pub fn is_sequence_visible(&self, sequence:usize) -> bool {
+		self.write_sequence >= sequence
+	}
+
+	/// Wether or not the buffer is frozen.
+	pub fn is_frozen(&self) -> bool {
+		self.frozen
+	}
+
+	/// Return the total amount of samples rejected while the buffer was frozen.
+	pub fn frozen_rejected_count(&self) -> usize {
+		self.frozen_rejected_count
+	}
+}
+#[cfg(feature = "statistics")]
+impl<T:Clone, S:AsRef<[T]> + AsMut<[T]>, C:CursorPolicy<T>> CircularBufferCore<T, S, C> {
+
+	/* STATISTICS METHODS */
+
+	/// Keep track of a write in the statistics and the registered state of the buffer. The occupancy is the amount of stored samples before the write.
+	fn record_write(&mut self, occupancy:usize, offered:usize, written:usize) {
+		self.statistics.record_write(occupancy, offered, written);
+		#[cfg(feature = "registry")]
+		self.publish_to_registry();
+	}
+
+	/// Return the occupancy and dropped sample statistics of the buffer.
+	pub fn statistics(&self) -> &BufferStatistics {
+		&self.statistics
+	}
+
+	/// Reset the occupancy and dropped sample statistics of the buffer.
+	pub fn reset_statistics(&mut self) {
+		self.statistics = BufferStatistics::new();
+	}
+}
+#[cfg(feature = "registry")]
+impl<T:Clone, S:AsRef<[T]> + AsMut<[T]>, C:CursorPolicy<T>> CircularBufferCore<T, S, C> {
+
+	/* REGISTRY METHODS */
+
+	/// Register the buffer in the global registry under the given name, so its occupancy and drop statistics show up in registry::snapshot. The registered state is updated on every write.
+	/// Returns false if the registry is full. Registering again releases the previous registration, which renames the buffer. Clones of a registered buffer are not registered.
+	/// The registration is released when the buffer is dropped.
+	pub fn register(&mut self, name:&str) -> bool {
+		self.registration = Registration::NONE;
+		self.registration = Registration::register(name);
+		self.publish_to_registry();
+		self.registration.is_registered()
+	}
+
+	/// Remove the buffer from the global registry.
+	pub fn unregister(&mut self) {
+		self.registration = Registration::NONE;
+	}
+
+	/// Update the registered state of the buffer, if registered.
+	fn publish_to_registry(&self) {
+		self.registration.publish(self.largest_len(), self.capacity, &self.statistics);
+	}
+}
+impl<T:Clone, S:AsRef<[T]> + AsMut<[T]>, C:CursorPolicy<T>> BufferWrite<T> for CircularBufferCore<T, S, C> {
+	fn extend(&mut self, input:&[T]) -> usize {
+		self.extend(input)
+	}
+}
+impl<S:AsRef<[u8]> + AsMut<[u8]>, C:CursorPolicy<u8>> Write for CircularBufferCore<u8, S, C> {
+
+	/// Add bytes to the buffer. A full buffer writes nothing, which write_all reports as an error.
+	fn write(&mut self, input:&[u8]) -> std::io::Result<usize> {
+		Ok(self.extend(input))
+	}
+	fn flush(&mut self) -> std::io::Result<()> {
+		Ok(())
+	}
+}
+impl<T:Clone, S:AsRef<[T]> + AsMut<[T]>, C:CursorPolicy<T>> Extend<T> for CircularBufferCore<T, S, C> {
+
+	/// Add all samples of the iterator to the buffer. Samples that do not fit are dropped.
+	fn extend<I:IntoIterator<Item = T>>(&mut self, input:I) {
+		for sample in input {
+			self.push(sample);
+		}
+	}
+}
+
+
+
+/// An iterator that takes the unread samples of a cursor one by one, oldest first, without allocating.
+/// Like the drain of a Vec, all samples that were not iterated over are taken when the iterator is dropped.
+pub struct Drain<'a, T:Clone, S:AsRef<[T]> + AsMut<[T]>, C:CursorPolicy<T>> {
+	buffer:&'a mut CircularBufferCore<T, S, C>,
+	cursor_index:usize
+}
+impl<T:Clone, S:AsRef<[T]> + AsMut<[T]>, C:CursorPolicy<T>> Iterator for Drain<'_, T, S, C> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<T> {
+		let sample:T = self.buffer.cursor_unread_slices(self.cursor_index)[0].first()?.clone();
+		self.buffer.advance_cursor(self.cursor_index, 1);
+		Some(sample)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining:usize = self.buffer.cursor_len(self.cursor_index);
+		(remaining, Some(remaining))
+	}
+}
+impl<T:Clone, S:AsRef<[T]> + AsMut<[T]>, C:CursorPolicy<T>> ExactSizeIterator for Drain<'_, T, S, C> {}
+impl<T:Clone, S:AsRef<[T]> + AsMut<[T]>, C:CursorPolicy<T>> Drop for Drain<'_, T, S, C> {
+	fn drop(&mut self) {
+		let remaining:usize = self.buffer.cursor_len(self.cursor_index);
+		self.buffer.advance_cursor(self.cursor_index, remaining);
+	}
+}
+#[cfg(feature = "dsp")]
+impl<T:Clone + WindowSample, S:AsRef<[T]> + AsMut<[T]>, C:CursorPolicy<T>> CircularBufferCore<T, S, C> {
+
+	/* DSP METHODS */
+
+	/// Take a window of samples from the buffer for a specific cursor multiplied by the window function, while only consuming the hop size. Writes the window to the start of the output.
+	/// Returns false and takes nothing if less than the window or hop size is stored, or if the output is smaller than the window.
+	pub(crate) fn cursor_take_windowed_with(&mut self, window:usize, hop:usize, output:&mut [T], function:WindowFunction, cursor_index:usize) -> bool {
+		self.record_read(cursor_index);
+		if self.cursor_len(cursor_index) < window.max(hop) || output.len() < window {
+			return false;
+		}
+		let window_start:usize = self.cursors.cursors()[cursor_index].position;
+		let straight_space:usize = window.min(self.capacity - window_start);
+		let buffer:&[T] = self.buffer.as_ref();
+		function.apply_to_output([&buffer[window_start..window_start + straight_space], &buffer[..window - straight_space]], &mut output[..window]);
+		self.advance_cursor(cursor_index, hop);
+		true
+	}
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ CircularBuffer, CircularBufferCore, CircularBufferDyn, DynStorage, DynamicReaders, FixedReaders, ReadCursor, SingleReader };
+
+
+
+	#[test]
+	fn test_array_storage_with_dynamic_readers() {
+		let mut buffer:CircularBufferCore<i32, [i32; 8], DynamicReaders> = CircularBufferCore::<i32, [i32; 8], DynamicReaders>::new();
+		let cursors:Vec<ReadCursor> = (0..5).map(|_| buffer.create_read_cursor()).collect();
+		buffer.extend(&[1, 2, 3]);
+		for cursor in &cursors {
+			assert_eq!(buffer.take_all(cursor), vec![1, 2, 3]);
+		}
+	}
+
+	#[test]
+	fn test_dyn_storage_with_fixed_readers() {
+		let mut buffer:CircularBufferCore<i32, DynStorage<Vec<i32>>, FixedReaders<2>> = CircularBufferCore::<i32, DynStorage<Vec<i32>>, FixedReaders<2>>::new(4);
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		assert_eq!(buffer.extend(&[1, 2, 3, 4]), 3);
+		assert_eq!(buffer.take(2, &cursor_a), vec![1, 2]);
+		assert_eq!(buffer.take_all(&cursor_b), vec![1, 2, 3]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_fixed_readers_overflow() {
+		let mut buffer:CircularBufferCore<i32, [i32; 8], FixedReaders<1>> = CircularBufferCore::<i32, [i32; 8], FixedReaders<1>>::new();
+		buffer.create_read_cursor();
+		buffer.create_read_cursor();
+	}
+
+	#[test]
+	fn test_dyn_buffer_with_array_storage() {
+		let mut dyn_buffer:CircularBufferDyn<i32, [i32; 8]> = CircularBufferDyn::from_storage([0; 8]);
+		let mut array_buffer:CircularBuffer<i32, 8> = CircularBuffer::new();
+		dyn_buffer.extend(&[1, 2, 3]);
+		array_buffer.extend(&[1, 2, 3]);
+		assert_eq!(dyn_buffer.take_all(), array_buffer.take_all());
+	}
+
+	#[test]
+	fn test_array_storage_with_clone_samples() {
+		let mut buffer:CircularBufferCore<String, [String; 4], SingleReader> = CircularBufferCore::<String, [String; 4], SingleReader>::new_with_fn(|_| String::new());
+		buffer.take_all();
+		buffer.extend(&["a".to_string(), "b".to_string()]);
+		assert_eq!(buffer.take_one(), "a");
+		assert_eq!(buffer.take_all(), vec!["b".to_string()]);
+	}
+
+	#[test]
+	fn test_equality_ignores_statistics() {
+		let mut buffer_a:CircularBuffer<i32, 4> = CircularBuffer::new();
+		let mut buffer_b:CircularBuffer<i32, 4> = CircularBuffer::new();
+		buffer_a.extend(&[1, 2, 3, 4, 5]);
+		buffer_b.extend(&[1, 2, 3]);
+		assert!(buffer_a == buffer_b);
+		buffer_a.take(1);
+		assert!(buffer_a != buffer_b);
+	}
+}
@@ -1,137 +1,88 @@
+use crate::{ AlignedStorage, ArenaStorage, BulkCopySamples, CircularBufferCore, CursorPolicy, DynStorage, SampleCopy, SingleReader };
+use std::mem::MaybeUninit;
+#[cfg(feature = "allocator_api")]
+use std::alloc::Allocator;
+
+
+
 /// Works the same as CircularBuffer, but uses a vec instead of an array.
 /// This allows it to be sized dynamically in the 'new' function.
 /// Despite that, the buffer will not move as its size is constant throughout its entire lifetime.
 /// This can not be used statically, but does perform better than a normal Vec, as the list does not change in size, allowing it to stay in the same place in memory.
-#[derive(PartialEq, Eq, Clone)]
-pub struct CircularBufferDyn<T> {
-	buffer:Vec<T>,
-	capacity:usize, // Same as buffer.len(), but dynamically fetching is not useful as the buffer length always stays the same.
-	read_cursor:usize,
-	write_cursor:usize
-}
-impl<T:Default + Clone> CircularBufferDyn<T> {
-	
-	/* CONSTRUCTOR METHODS */
+/// The storage is a Vec by default, but can be any list that keeps its size, like an AlignedStorage.
+/// Samples are cloned, which allows any Clone sample. For Copy samples, use CircularBufferDynBulk to use the bulk copy features.
+pub type CircularBufferDyn<T, S = Vec<T>> = CircularBufferCore<T, DynStorage<S>, SingleReader>;
 
-	/// Create a new circular-buffer.
-	pub fn new(capacity:usize) -> CircularBufferDyn<T> {
-		CircularBufferDyn {
-			buffer: vec![T::default(); capacity],
-			capacity,
-			read_cursor: 0,
-			write_cursor: 0
-		}
-	}
+/// Works the same as CircularBufferDyn, but copies Copy samples in bulk like CircularBuffer does, which allows using the bulk copy features.
+/// Combined with an AlignedStorage, bulk copies start at the chosen alignment.
+pub type CircularBufferDynBulk<T, S = Vec<T>> = CircularBufferCore<T, DynStorage<S>, SingleReader<BulkCopySamples>>;
+impl<T:Clone, M:SampleCopy<T>> CircularBufferCore<T, DynStorage<Vec<T>>, SingleReader<M>> {
 
+	/* CONSTRUCTOR METHODS */
 
-
-	/* BUFFER METHODS */
-
-	/// Add a single sample to the buffer. Returns the amount of samples stored to the buffer.
-	pub fn push(&mut self, input:T) -> usize {
-		self.extend(&[input])
+	/// Create a new circular-buffer with all slots filled with the given value. Allows sample types without a sensible default value.
+	pub fn new_with(capacity:usize, fill:T) -> CircularBufferCore<T, DynStorage<Vec<T>>, SingleReader<M>> {
+		CircularBufferCore::from_storage(vec![fill; capacity])
 	}
 
-	/// Add a list of samples to the buffer. Returns the amount of samples stored to the buffer.
-	pub fn extend(&mut self, input:&[T]) -> usize {
-
-		// Find out how much free space is left before wrap.
-		let used_space:usize = self.len();
-		let available_space:usize = self.capacity - used_space;
-		let required_space:usize = input.len();
-
-		// If input is too large, only write beginning. Always keep one "empty" slot. This makes sure both cursors with the same value always means the buffer is empty, rather than full.
-		if required_space >= available_space {
-			return self.extend(&input[..available_space - 1]);
-		}
-
-		// If not enough space before wrap, return or split into two modifications.
-		let available_space_before_wrap:usize = self.capacity - self.write_cursor;
-		if available_space_before_wrap < required_space {
-			return self.extend(&input[..available_space_before_wrap]) + self.extend(&input[available_space_before_wrap..required_space]);
-		}
-
-		// If enough space before wrap, write to buffer.
-		self.buffer[self.write_cursor..self.write_cursor + required_space].clone_from_slice(&input);
-		self.write_cursor = (self.write_cursor + required_space) % self.capacity;
-		required_space
+	/// Create a new circular-buffer pre-filled with samples generated from their index, as if they were already written, like a delay line primed with a known waveform.
+	/// As one slot is always kept empty, the buffer starts with capacity - 1 unread samples.
+	pub fn new_with_fn<F:FnMut(usize) -> T>(capacity:usize, generator:F) -> CircularBufferCore<T, DynStorage<Vec<T>>, SingleReader<M>> {
+		let mut buffer:CircularBufferCore<T, DynStorage<Vec<T>>, SingleReader<M>> = CircularBufferCore::from_storage((0..capacity).map(generator).collect());
+		buffer.prime();
+		buffer
 	}
-
-	/// Take one sample from the buffer.
-	pub fn take_one(&mut self) -> T {
-		let found:Vec<T> = self.take(1);
-		if found.is_empty() {
-			T::default()
-		} else {
-			found[0].clone()
-		}
+}
+impl<T:Default + Clone, M:SampleCopy<T>> FromIterator<T> for CircularBufferCore<T, DynStorage<Vec<T>>, SingleReader<M>> {
+
+	/// Create a new circular-buffer holding all samples of the iterator, with a capacity of one more than the amount of samples for the slot that is always kept empty.
+	/// The storage is shrunk to the capacity, so memory_usage matches the allocation.
+	fn from_iter<I:IntoIterator<Item = T>>(input:I) -> CircularBufferCore<T, DynStorage<Vec<T>>, SingleReader<M>> {
+		let mut storage:Vec<T> = input.into_iter().collect();
+		storage.push(T::default());
+		storage.shrink_to_fit();
+		let mut buffer:CircularBufferCore<T, DynStorage<Vec<T>>, SingleReader<M>> = CircularBufferCore::from_storage(storage);
+		buffer.prime();
+		buffer
 	}
+}
+impl<T:Default + Clone, C:CursorPolicy<T> + Default> CircularBufferCore<T, DynStorage<Vec<T>>, C> {
 
-	/// Take all remaining samples from the buffer.
-	pub fn take_all(&mut self) -> Vec<T> {
-		self.take(self.len())
-	}
+	/* CONSTRUCTOR METHODS */
 
-	/// Take an amount of samples from the buffer.
-	pub fn take(&mut self, amount:usize) -> Vec<T> {
-		let mut output_buffer:Vec<T> = vec![T::default(); amount];
-		let written_amount:usize = self.take_to_buffer(&mut output_buffer);
-		output_buffer[..written_amount].to_vec()
+	/// Create a new circular-buffer.
+	pub fn new(capacity:usize) -> CircularBufferCore<T, DynStorage<Vec<T>>, C> {
+		CircularBufferCore::from_storage(vec![T::default(); capacity])
 	}
+}
+impl<T:Default + Clone, C:CursorPolicy<T> + Default> CircularBufferCore<T, DynStorage<AlignedStorage<T>>, C> {
 
-	/// Take an amount of samples from the buffer. Writes the data to the given output. Returns the amount of data taken from the buffer.
-	fn take_to_buffer(&mut self, output:&mut [T]) -> usize {
-
-		// Find out how much free space is left before wrap.
-		let used_space:usize = self.len();
-		if used_space == 0 {
-			return 0;
-		}
-		let used_required_space:usize = used_space.min(output.len());
-
-		// Take straight part.
-		let straight_space:usize = used_required_space.min(self.capacity - self.read_cursor);
-		output[..straight_space].clone_from_slice(&self.buffer[self.read_cursor..self.read_cursor + straight_space]);
-		self.read_cursor += straight_space;
-		let wrapped_space:usize = used_required_space - straight_space;
-		if wrapped_space != 0 {
-			self.read_cursor -= self.capacity;
-			output[straight_space..straight_space + wrapped_space].clone_from_slice(&self.buffer[self.read_cursor..self.read_cursor + wrapped_space]);
-			self.read_cursor += wrapped_space;
-		}
-		
-		// Return taken amount.
-		straight_space + wrapped_space
-	}
+	/* CONSTRUCTOR METHODS */
 
-	/// Get all data that is written in the buffer, including the amount already having been read. The newest samples will be at the end of the list.
-	pub fn raw_data(&self) -> Vec<T> {
-		let mut output:Vec<T> = self.buffer.to_vec();
-		output.rotate_left(self.read_cursor);
-		output
+	/// Create a new circular-buffer of which the storage starts at the given alignment in bytes. Panics if the alignment is not a power of two.
+	pub fn new_aligned(capacity:usize, alignment:usize) -> CircularBufferCore<T, DynStorage<AlignedStorage<T>>, C> {
+		CircularBufferCore::from_storage(AlignedStorage::new(capacity, alignment, T::default()))
 	}
+}
+impl<'a, T:Default + Clone, C:CursorPolicy<T> + Default> CircularBufferCore<T, DynStorage<ArenaStorage<'a, T>>, C> {
 
+	/* CONSTRUCTOR METHODS */
 
-
-	
-	/* PROPERTY GETTER METHODS */
-
-	/// Return the amount of currently stored samples.
-	pub fn len(&self) -> usize {
-		if self.write_cursor >= self.read_cursor {
-			self.write_cursor - self.read_cursor
-		} else {
-			self.capacity - (self.read_cursor - self.write_cursor)
-		}.min(self.capacity)
+	/// Create a new circular-buffer of which the storage is carved out of the start of the given byte arena, without using the global allocator. Returns None if the arena is too small.
+	/// To create multiple buffers in the same arena, carve the storage using ArenaStorage::carve and create the buffer using from_storage.
+	pub fn new_in_arena(arena:&'a mut [MaybeUninit<u8>], capacity:usize) -> Option<CircularBufferCore<T, DynStorage<ArenaStorage<'a, T>>, C>> {
+		ArenaStorage::carve(arena, capacity, T::default()).map(|(storage, _)| CircularBufferCore::from_storage(storage))
 	}
+}
+#[cfg(feature = "allocator_api")]
+impl<T:Default + Clone, A:Allocator, C:CursorPolicy<T> + Default> CircularBufferCore<T, DynStorage<Vec<T, A>>, C> {
 
-	/// Wether or not there are 0 stored samples.
-	pub fn is_empty(&self) -> bool {
-		self.len() == 0
-	}
+	/* CONSTRUCTOR METHODS */
 
-	/// Wether or not the buffer is full.
-	pub fn is_full(&self) -> bool {
-		self.len() == self.capacity - 1
+	/// Create a new circular-buffer of which the storage is allocated in the given allocator.
+	pub fn new_in(capacity:usize, allocator:A) -> CircularBufferCore<T, DynStorage<Vec<T, A>>, C> {
+		let mut storage:Vec<T, A> = Vec::with_capacity_in(capacity, allocator);
+		storage.resize(capacity, T::default());
+		CircularBufferCore::from_storage(storage)
 	}
 }
\ No newline at end of file
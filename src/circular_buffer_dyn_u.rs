@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
 	use std::time::{ Duration, Instant };
-	use crate::CircularBufferDyn;
+	use crate::{ AlignedStorage, ArenaStorage, CircularBufferDyn, CircularBufferDynBulk };
+	use std::mem::MaybeUninit;
 	
 	
 
@@ -131,6 +132,337 @@ mod tests {
 		assert!(!buffer.is_empty());
 	}
 
+	#[test]
+	fn test_take_to_uninit() {
+		let mut buffer:CircularBufferDyn<i32> = get_test_buffer();
+		buffer.extend(&[1, 2, 3, 4, 5, 6]);
+		buffer.take(4);
+		buffer.extend(&[7, 8, 9]);
+
+		let mut output:Vec<i32> = Vec::with_capacity(10);
+		let taken:usize = buffer.take_to_uninit(output.spare_capacity_mut());
+		unsafe { output.set_len(taken); }
+		assert_eq!(output, vec![5, 6, 7, 8, 9]);
+		assert!(buffer.is_empty());
+	}
+
+	#[test]
+	fn test_aligned_storage() {
+		let mut buffer:CircularBufferDyn<i32, AlignedStorage<i32>> = CircularBufferDyn::new_aligned(TEST_CAPACITY, 64);
+		assert_eq!(buffer.raw_data().len(), TEST_CAPACITY);
+
+		buffer.extend(&[1, 2, 3, 4, 5, 6]);
+		buffer.take(4);
+		buffer.extend(&[7, 8, 9]);
+		assert_eq!(buffer.take_all(), vec![5, 6, 7, 8, 9]);
+	}
+
+	#[test]
+	fn test_arena_storage() {
+		let mut arena:[MaybeUninit<u8>; 64] = [MaybeUninit::uninit(); 64];
+		let mut buffer:CircularBufferDyn<i32, ArenaStorage<i32>> = CircularBufferDyn::new_in_arena(&mut arena, TEST_CAPACITY).unwrap();
+		buffer.extend(&[1, 2, 3, 4, 5, 6]);
+		buffer.take(4);
+		buffer.extend(&[7, 8, 9]);
+		assert_eq!(buffer.take_all(), vec![5, 6, 7, 8, 9]);
+	}
+
+	#[cfg(feature = "allocator_api")]
+	#[test]
+	fn test_new_in_allocator() {
+		let mut buffer:CircularBufferDyn<i32, Vec<i32, std::alloc::System>> = CircularBufferDyn::new_in(TEST_CAPACITY, std::alloc::System);
+		buffer.extend(&[1, 2, 3, 4, 5, 6]);
+		buffer.take(4);
+		buffer.extend(&[7, 8, 9]);
+		assert_eq!(buffer.take_all(), vec![5, 6, 7, 8, 9]);
+	}
+
+	#[test]
+	fn test_freeze_rejects_writes() {
+		let mut buffer:CircularBufferDyn<i32> = get_test_buffer();
+		buffer.extend(&[1, 2, 3]);
+		buffer.freeze();
+		assert!(buffer.is_frozen());
+
+		// Writes are rejected, reads continue.
+		assert_eq!(buffer.extend(&[4, 5]), 0);
+		assert_eq!(buffer.push(6), 0);
+		assert_eq!(buffer.frozen_rejected_count(), 3);
+		assert_eq!(buffer.take_all(), vec![1, 2, 3]);
+
+		// Writes are accepted again after unfreezing.
+		buffer.unfreeze();
+		assert!(!buffer.is_frozen());
+		assert_eq!(buffer.extend(&[7, 8]), 2);
+		assert_eq!(buffer.take_all(), vec![7, 8]);
+		assert_eq!(buffer.frozen_rejected_count(), 3);
+	}
+
+	#[test]
+	#[cfg(feature = "statistics")]
+	fn test_statistics_and_capacity_suggestion() {
+		let mut buffer:CircularBufferDyn<i32> = get_test_buffer();
+		buffer.extend(&[1, 2, 3, 4, 5]);
+		buffer.take(5);
+		buffer.extend(&(0..12).collect::<Vec<i32>>());
+
+		assert_eq!(buffer.statistics().offered_count(), 17);
+		assert_eq!(buffer.statistics().written_count(), 12);
+		assert_eq!(buffer.statistics().dropped_count(), 5);
+		assert_eq!(buffer.statistics().high_water_mark(), TEST_CAPACITY - 1);
+		assert_eq!(buffer.statistics().suggest_capacity(0.0), 16);
+
+		// Frozen rejections are not counted as dropped.
+		buffer.freeze();
+		buffer.extend(&[1, 2, 3]);
+		assert_eq!(buffer.statistics().dropped_count(), 5);
+
+		buffer.reset_statistics();
+		assert_eq!(buffer.statistics().offered_count(), 0);
+	}
+
+	#[test]
+	fn test_take_windowed() {
+		let mut buffer:CircularBufferDyn<i32> = get_test_buffer();
+		let mut window:[i32; 4] = [0; 4];
+		buffer.extend(&[1, 2, 3, 4, 5, 6]);
+
+		// Overlapping windows.
+		assert!(buffer.take_windowed(4, 2, &mut window));
+		assert_eq!(window, [1, 2, 3, 4]);
+		assert!(buffer.take_windowed(4, 2, &mut window));
+		assert_eq!(window, [3, 4, 5, 6]);
+		assert_eq!(buffer.len(), 2);
+
+		// Not enough data for a full window.
+		assert!(!buffer.take_windowed(4, 2, &mut window));
+		assert_eq!(buffer.len(), 2);
+
+		// Windows over the wrap.
+		buffer.extend(&[7, 8, 9]);
+		assert!(buffer.take_windowed(4, 3, &mut window));
+		assert_eq!(window, [5, 6, 7, 8]);
+		assert_eq!(buffer.take_all(), vec![8, 9]);
+
+		// Output too small.
+		buffer.extend(&[1, 2, 3, 4, 5]);
+		assert!(!buffer.take_windowed(5, 1, &mut window));
+	}
+
+	#[cfg(feature = "dsp")]
+	#[test]
+	fn test_take_windowed_with_function() {
+		let mut buffer:CircularBufferDyn<f64> = CircularBufferDyn::new(TEST_CAPACITY);
+		let mut window:[f64; 5] = [0.0; 5];
+		buffer.extend(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+		buffer.take(4);
+		buffer.extend(&[7.0, 8.0, 9.0]);
+
+		// Window over the wrap.
+		assert!(buffer.take_windowed_with(5, 2, &mut window, crate::WindowFunction::Hann));
+		assert!(window.iter().zip([0.0, 3.0, 7.0, 4.0, 0.0]).all(|(found, expected)| (found - expected).abs() < 1e-9));
+		assert_eq!(buffer.take_all(), vec![7.0, 8.0, 9.0]);
+		assert!(!buffer.take_windowed_with(5, 2, &mut window, crate::WindowFunction::Hann));
+	}
+
+	#[test]
+	fn test_downsample() {
+		let mut buffer:CircularBufferDyn<i32> = get_test_buffer();
+		buffer.extend(&[1, 2, 3, 4, 5, 6]);
+		buffer.take(4);
+		buffer.extend(&[9, -3, 7]);
+
+		assert_eq!(buffer.downsample(2), vec![(5, 6), (-3, 9)]);
+		assert_eq!(buffer.downsample(10), vec![(5, 5), (6, 6), (9, 9), (-3, -3), (7, 7)]);
+		assert_eq!(buffer.len(), 5);
+	}
+
+	#[test]
+	fn test_push_overwrite() {
+		let mut buffer:CircularBufferDyn<i32> = get_test_buffer();
+		for value in 0..TEST_CAPACITY as i32 - 1 {
+			assert_eq!(buffer.push_overwrite(value), None);
+		}
+		assert_eq!(buffer.push_overwrite(100), Some(0));
+		assert_eq!(buffer.push_overwrite(101), Some(1));
+		assert_eq!(buffer.take_all(), vec![2, 3, 4, 5, 6, 100, 101]);
+
+		buffer.freeze();
+		assert_eq!(buffer.push_overwrite(102), None);
+		assert!(buffer.is_empty());
+	}
+
+	#[test]
+	fn test_push_overwrite_without_default() {
+		#[derive(Clone, PartialEq, Debug)]
+		struct Reading(u32);
+		let mut buffer:CircularBufferDyn<Reading> = CircularBufferDyn::new_with(3, Reading(0));
+		assert_eq!(buffer.push_overwrite(Reading(1)), None);
+		assert_eq!(buffer.push_overwrite(Reading(2)), None);
+		assert_eq!(buffer.push_overwrite(Reading(3)), Some(Reading(1)));
+		assert_eq!(buffer.drain().collect::<Vec<Reading>>(), vec![Reading(2), Reading(3)]);
+	}
+
+	#[test]
+	fn test_sorted_search() {
+		let mut buffer:CircularBufferDyn<i32> = get_test_buffer();
+		buffer.extend(&[0, 0, 0, 0, 0]);
+		buffer.take(5);
+		buffer.extend(&[10, 20, 30, 40, 50, 60]);
+
+		let skip_amount:usize = buffer.partition_point(|sample| *sample <= 30);
+		assert_eq!(skip_amount, 3);
+		assert_eq!(buffer.binary_search_by(|sample| sample.cmp(&50)), Ok(4));
+		assert_eq!(buffer.binary_search_by(|sample| sample.cmp(&15)), Err(1));
+		buffer.take(skip_amount);
+		assert_eq!(buffer.take_all(), vec![40, 50, 60]);
+	}
+
+	#[test]
+	fn test_take_array() {
+		let mut buffer:CircularBufferDyn<i32> = get_test_buffer();
+		buffer.extend(&[1, 2, 3, 4, 5]);
+		buffer.take(3);
+		buffer.extend(&[6, 7, 8, 9]);
+
+		assert_eq!(buffer.take_array::<4>(), Some([4, 5, 6, 7]));
+		assert_eq!(buffer.take_array::<3>(), None);
+		assert_eq!(buffer.take_array::<2>(), Some([8, 9]));
+		assert_eq!(buffer.take_array::<0>(), Some([]));
+	}
+
+	#[test]
+	fn test_push_array() {
+		let mut buffer:CircularBufferDyn<i32> = get_test_buffer();
+		assert!(buffer.push_array([1, 2, 3, 4]));
+		assert!(!buffer.push_array([5, 6, 7, 8]));
+		assert!(buffer.push_array([5, 6, 7]));
+		assert!(!buffer.push_array([8]));
+		#[cfg(feature = "statistics")]
+		assert_eq!(buffer.statistics().dropped_count(), 5);
+		assert_eq!(buffer.take_array::<7>(), Some([1, 2, 3, 4, 5, 6, 7]));
+
+		buffer.freeze();
+		assert!(!buffer.push_array([1]));
+		assert!(buffer.is_empty());
+	}
+
+	#[test]
+	fn test_new_with_non_default_type() {
+
+		#[derive(Clone, PartialEq, Debug)]
+		struct Reading(u8);
+
+		let mut buffer:CircularBufferDyn<Reading> = CircularBufferDyn::new_with(4, Reading(0));
+		buffer.extend(&[Reading(1), Reading(2)]);
+		assert_eq!(buffer.take(1), vec![Reading(1)]);
+		buffer.extend(&[Reading(3), Reading(4)]);
+		assert!(buffer.is_full());
+		assert_eq!(buffer.take_array::<2>(), Some([Reading(2), Reading(3)]));
+		assert_eq!(buffer.take_all(), vec![Reading(4)]);
+		assert_eq!(buffer.take(1), vec![]);
+	}
+
+	#[test]
+	fn test_new_with_fn() {
+		let mut buffer:CircularBufferDyn<i32> = CircularBufferDyn::new_with_fn(TEST_CAPACITY, |index| index as i32 * 10);
+		assert!(buffer.is_full());
+		assert_eq!(buffer.take(3), vec![0, 10, 20]);
+		buffer.extend(&[1, 2, 3]);
+		assert_eq!(buffer.take_all(), vec![30, 40, 50, 60, 1, 2, 3]);
+	}
+
+	#[test]
+	fn test_take_at_least() {
+		let mut buffer:CircularBufferDyn<i32> = get_test_buffer();
+		buffer.extend(&[1, 2]);
+		assert_eq!(buffer.take_at_least(3, 4), None);
+		buffer.extend(&[3, 4, 5]);
+		assert_eq!(buffer.take_at_least(3, 4), Some(vec![1, 2, 3, 4]));
+		assert_eq!(buffer.take_at_least(1, 4), Some(vec![5]));
+		assert_eq!(buffer.take_at_least(0, 4), Some(vec![]));
+
+		// A max below min still takes at least min samples.
+		buffer.extend(&[6, 7, 8]);
+		assert_eq!(buffer.take_at_least(3, 1), Some(vec![6, 7, 8]));
+	}
+
+	#[test]
+	fn test_memory_usage() {
+		let buffer:CircularBufferDyn<u64> = CircularBufferDyn::new(100);
+		assert_eq!(buffer.memory_usage(), size_of::<CircularBufferDyn<u64>>() + 800);
+	}
+
+	#[test]
+	fn test_zero_sized_samples() {
+		let mut buffer:CircularBufferDyn<()> = CircularBufferDyn::new(8);
+		for cycle in 0..20 {
+			assert_eq!(buffer.extend(&[(); 5]), 5);
+			assert_eq!(buffer.push(()), 1);
+			assert_eq!(buffer.len(), 6);
+			assert_eq!(buffer.take(4).len(), 4);
+			assert_eq!(buffer.take_array::<2>(), Some([(); 2]));
+			assert!(buffer.is_empty(), "Failed at cycle {cycle}");
+		}
+		assert_eq!(buffer.extend(&[(); 100]), 7);
+		assert!(buffer.is_full());
+		assert_eq!(buffer.push_overwrite(()), Some(()));
+		assert_eq!(buffer.take_all().len(), 7);
+		#[cfg(feature = "statistics")]
+		assert_eq!(buffer.statistics().dropped_count(), 93);
+	}
+
+	#[test]
+	fn test_evict_while() {
+		let mut buffer:CircularBufferDyn<i32> = get_test_buffer();
+		buffer.extend(&[1, 2, 3, 4, 5]);
+		buffer.take(4);
+		buffer.extend(&[6, 7, 8, 9]);
+		assert_eq!(buffer.evict_while(|sample| *sample < 8), 3);
+		assert_eq!(buffer.evict_while(|sample| *sample > 100), 0);
+		assert_eq!(buffer.take_all(), vec![8, 9]);
+		assert_eq!(buffer.evict_while(|_| true), 0);
+	}
+
+	#[test]
+	fn test_last_written() {
+		let mut buffer:CircularBufferDyn<i32> = get_test_buffer();
+		assert_eq!(buffer.last_written(), None);
+		buffer.extend(&[1, 2, 3, 4, 5, 6]);
+		buffer.take_all();
+		assert_eq!(buffer.last_written(), Some(&6));
+		buffer.extend(&[7, 8, 9]);
+		assert_eq!(buffer.last_written(), Some(&9));
+		assert_eq!(buffer.take_all(), vec![7, 8, 9]);
+	}
+
+	#[cfg(feature = "cobs")]
+	#[test]
+	fn test_cobs_frames() {
+		let mut buffer:CircularBufferDyn<u8> = CircularBufferDyn::new(16);
+		assert!(buffer.push_cobs_frame(&[0x11, 0x00, 0x22]));
+		assert!(buffer.push_cobs_frame(&[]));
+		assert!(!buffer.push_cobs_frame(&[0x33; 32]));
+		assert_eq!(buffer.take_cobs_frame(), Some(Ok(vec![0x11, 0x00, 0x22])));
+		assert_eq!(buffer.take_cobs_frame(), Some(Ok(vec![])));
+		assert_eq!(buffer.take_cobs_frame(), None);
+
+		// Raw bytes arriving one by one, with a damaged frame and extra delimiters in between.
+		for byte in [0x00, 0x05, 0x11, 0x00, 0x00, 0x02, 0x44] {
+			buffer.push(byte);
+		}
+		assert_eq!(buffer.take_cobs_frame(), Some(Err(crate::InvalidCobsFrame(2))));
+		assert_eq!(buffer.take_cobs_frame(), None);
+		buffer.push(0x00);
+		assert_eq!(buffer.take_cobs_frame(), Some(Ok(vec![0x44])));
+		assert!(buffer.is_empty());
+
+		// A full buffer without any delimiter is dropped.
+		buffer.extend(&[0x01; 32]);
+		assert_eq!(buffer.take_cobs_frame(), Some(Err(crate::InvalidCobsFrame(15))));
+		assert!(buffer.is_empty());
+	}
+
 	#[test]
 	fn test_stress_test_large_cycles() {
 		const LOOPS:usize = 100_000;
@@ -139,7 +471,7 @@ mod tests {
 		let mut counter:i32 = 0;
 
 		for _ in 0..LOOPS {
-			let data:Vec<i32> = (0..512).map(|x| counter + x as i32).collect();
+			let data:Vec<i32> = (0..512).map(|x| counter + x).collect();
 			buffer.extend(&data);
 			counter += 512;
 
@@ -166,4 +498,59 @@ mod tests {
 		let elapsed:Duration = start.elapsed();
 		println!("Performed {} ops in {:?}", OPERATIONS, elapsed);
 	}
+
+	#[test]
+	fn test_collect() {
+		let mut buffer:CircularBufferDyn<i32> = (0..5).collect();
+		assert!(buffer.is_full());
+		assert_eq!(buffer.as_slices(), [&[0, 1, 2, 3, 4][..], &[][..]]);
+		assert_eq!(buffer.drain().collect::<Vec<i32>>(), vec![0, 1, 2, 3, 4]);
+		assert_eq!(buffer.extend(&[5, 6]), 2);
+		assert_eq!(buffer.take_all(), vec![5, 6]);
+	}
+
+	#[test]
+	fn test_collect_does_not_over_allocate() {
+		let buffer:CircularBufferDyn<u64> = (0..100).filter(|sample| sample % 2 == 0).collect();
+		assert_eq!(buffer.memory_usage(), size_of::<CircularBufferDyn<u64>>() + 51 * size_of::<u64>());
+		assert_eq!(buffer.buffer.into_inner().capacity(), 51);
+	}
+
+	#[test]
+	fn test_bulk_copy_buffer_wraps() {
+		let mut buffer:CircularBufferDynBulk<i32> = CircularBufferDynBulk::new(TEST_CAPACITY);
+		assert_eq!(buffer.extend(&[1, 2, 3, 4, 5]), 5);
+		assert_eq!(buffer.take(4), vec![1, 2, 3, 4]);
+		assert_eq!(buffer.extend(&[6, 7, 8, 9, 10, 11]), 6);
+		assert_eq!(buffer.as_slices(), [&[5, 6, 7, 8][..], &[9, 10, 11][..]]);
+		let mut output:[i32; 7] = [0; 7];
+		assert_eq!(buffer.take_to_buffer(&mut output), 7);
+		assert_eq!(output, [5, 6, 7, 8, 9, 10, 11]);
+		assert!(buffer.is_empty());
+	}
+
+	#[test]
+	fn test_bulk_copy_buffer_with_aligned_storage() {
+		let mut buffer:CircularBufferDynBulk<f32, AlignedStorage<f32>> = CircularBufferDynBulk::new_aligned(64, 64);
+		assert_eq!(buffer.as_slices()[0].as_ptr() as usize % 64, 0);
+		let input:Vec<f32> = (0..48).map(|sample| sample as f32).collect();
+		assert_eq!(buffer.extend(&input), 48);
+		assert_eq!(buffer.take(40), input[..40].to_vec());
+		assert_eq!(buffer.extend(&input), 48);
+		assert_eq!(buffer.len(), 56);
+		assert_eq!(buffer.take(8), input[40..].to_vec());
+		assert_eq!(buffer.take_all(), input);
+	}
+
+	#[test]
+	fn test_bulk_copy_buffer_constructors() {
+		let mut buffer:CircularBufferDynBulk<u8> = CircularBufferDynBulk::new_with_fn(4, |index| index as u8);
+		assert_eq!(buffer.take_all(), vec![0, 1, 2]);
+		let mut buffer:CircularBufferDynBulk<u8> = CircularBufferDynBulk::new_with(4, 7);
+		assert!(buffer.is_empty());
+		buffer.extend(&[1, 2]);
+		assert_eq!(buffer.take_all(), vec![1, 2]);
+		let mut buffer:CircularBufferDynBulk<u8> = (1..4).collect();
+		assert_eq!(buffer.take_all(), vec![1, 2, 3]);
+	}
 }
\ No newline at end of file
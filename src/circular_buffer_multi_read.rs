@@ -1,90 +1,70 @@
-use crate::ReadCursor;
+use crate::{ CircularBufferCore, CursorBufferRead, CursorPolicy, CursorState, Drain, FixedReaders, MultiCursorPolicy, ReadCursor, downsample::downsample_min_max, sorted_search::{ binary_search_by_split, partition_point_split } };
+#[cfg(feature = "statistics")]
+use crate::CursorStatistics;
+#[cfg(feature = "cursor-timeout")]
+use crate::{ Clock, clock::ClockRef };
+#[cfg(feature = "dsp")]
+use crate::{ WindowFunction, WindowSample };
+use std::{ cmp::Ordering, mem::MaybeUninit };
+#[cfg(feature = "cursor-timeout")]
+use std::time::Duration;
 
 
 
 /// Works the same as CircularBuffer, but allows using multiple threads to read.
 /// For each thread that wants to read from the buffer, create a cursor that keeps track of that cursors' last read values.
-#[derive(PartialEq, Eq, Clone, Copy)]
-pub struct CircularBufferMultiRead<T, const CAPACITY:usize, const MAX_READ_CURSOR_COUNT:usize> {
-	buffer:[T; CAPACITY],
-	read_cursors:[usize; MAX_READ_CURSOR_COUNT],
-	current_read_cursor_count:usize,
-	write_cursor:usize
-}
-impl<T:Copy, const CAPACITY:usize, const MAX_READ_CURSOR_COUNT:usize> CircularBufferMultiRead<T, CAPACITY, MAX_READ_CURSOR_COUNT> {
+pub type CircularBufferMultiRead<T, const CAPACITY:usize, const MAX_READ_CURSOR_COUNT:usize> = CircularBufferCore<T, [T; CAPACITY], FixedReaders<MAX_READ_CURSOR_COUNT>>;
+impl<T:Copy, const CAPACITY:usize, const MAX_READ_CURSOR_COUNT:usize> CircularBufferCore<T, [T; CAPACITY], FixedReaders<MAX_READ_CURSOR_COUNT>> {
 
 	/// Create a new circular buffer as compile-time constant.
 	pub const fn new_const(default_value:T) -> CircularBufferMultiRead<T, CAPACITY, MAX_READ_CURSOR_COUNT> {
-		CircularBufferMultiRead {
-			buffer: [default_value; CAPACITY],
-			read_cursors: [0; MAX_READ_CURSOR_COUNT],
-			current_read_cursor_count: 0,
-			write_cursor: 0
-		}
+		CircularBufferCore::from_parts([default_value; CAPACITY], CAPACITY, FixedReaders::new())
 	}
 }
-impl<T:Default + Copy, const CAPACITY:usize, const MAX_READ_CURSOR_COUNT:usize> CircularBufferMultiRead<T, CAPACITY, MAX_READ_CURSOR_COUNT> {
-	
-	/* CONSTRUCTOR METHODS */
-
-	/// Create a new circular-buffer.
-	pub fn new() -> CircularBufferMultiRead<T, CAPACITY, MAX_READ_CURSOR_COUNT> {
-		CircularBufferMultiRead {
-			buffer: [T::default(); CAPACITY],
-			read_cursors: [0; MAX_READ_CURSOR_COUNT],
-			current_read_cursor_count: 0,
-			write_cursor: 0
-		}
-	}
-
-
+impl<T:Copy, S:AsRef<[T]> + AsMut<[T]>, C:CursorPolicy<T> + MultiCursorPolicy> CircularBufferCore<T, S, C> {
 
 	/* BUFFER WRITING METHODS */
 
 	/// Create a ReadCursor.
-	pub fn create_read_cursor<'a>(&'a mut self) -> ReadCursor {
-		let cursor_id:usize = self.current_read_cursor_count;
-		if cursor_id > MAX_READ_CURSOR_COUNT {
-			panic!("Could not create CircularBufferMultiRead Cursor, max cursor count overflow.");
-		}
-		self.current_read_cursor_count += 1;
-		self.read_cursors[cursor_id] = self.write_cursor;
-		ReadCursor(cursor_id)
+	pub fn create_read_cursor(&mut self) -> ReadCursor {
+		let Some(cursor_index) = self.cursors.add_cursor(CursorState::new(self.write_cursor)) else {
+			panic!("Could not create read cursor, max cursor count overflow.");
+		};
+		self.record_read(cursor_index);
+		ReadCursor(cursor_index)
 	}
 
 	/// Skip a cursor to the end of data, ignoring all current data.
 	pub fn skip_current_data(&mut self, cursor:&ReadCursor) {
-		self.read_cursors[cursor.0] = self.write_cursor;
-	}
-
-	/// Add a single sample to the buffer. Returns the amount of samples stored to the buffer.
-	pub fn push(&mut self, input:T) -> usize {
-		self.extend(&[input])
+		self.cursor_skip_current_data(cursor.0);
 	}
 
-	/// Add a list of samples to the buffer. Returns the amount of samples stored to the buffer.
-	pub fn extend(&mut self, input:&[T]) -> usize {
-
-		// Find out how much free space is left before wrap.
-		let largest_used_space:usize = (0..self.current_read_cursor_count).map(|cursor_index| self.len(&ReadCursor(cursor_index))).max().unwrap_or_default();
-		let available_space:usize = CAPACITY - largest_used_space;
-		let required_space:usize = input.len();
-
-		// If input is too large, only write beginning. Always keep one "empty" slot. This makes sure both cursors with the same value always means the buffer is empty, rather than full.
-		if required_space >= available_space {
-			return self.extend(&input[..available_space - 1]);
+	/// Set the inactivity timeout of cursors. Cursors that have not taken any samples within the timeout expire at the next write, so an abandoned reader can not stall the writer forever.
+	/// An expired cursor has no unread samples until it is revived. Setting the timeout counts as activity for all cursors.
+	/// Requires the 'cursor-timeout' feature, so buffers without it do not need to keep a clock and read times.
+	#[cfg(feature = "cursor-timeout")]
+	pub fn set_cursor_timeout(&mut self, timeout:Option<Duration>) {
+		self.cursor_timeout = timeout;
+		for cursor_index in 0..self.cursors.cursors().len() {
+			self.record_read(cursor_index);
 		}
+	}
 
-		// If not enough space before wrap, return or split into two modifications.
-		let available_space_before_wrap:usize = CAPACITY - self.write_cursor;
-		if available_space_before_wrap < required_space {
-			return self.extend(&input[..available_space_before_wrap]) + self.extend(&input[available_space_before_wrap..required_space]);
+	/// Set the clock used for time-based features, like the cursor timeout and the last read times of cursors. Uses the SystemClock by default.
+	/// As times of different clocks can not be compared, setting the clock counts as activity for all cursors.
+	#[cfg(feature = "cursor-timeout")]
+	pub fn set_clock(&mut self, clock:&'static dyn Clock) {
+		self.clock = ClockRef(clock);
+		for cursor_index in 0..self.cursors.cursors().len() {
+			self.record_read(cursor_index);
 		}
+	}
 
-		// If enough space before wrap, write to buffer.
-		self.buffer[self.write_cursor..self.write_cursor + required_space].copy_from_slice(&input);
-		self.write_cursor = (self.write_cursor + required_space) % CAPACITY;
-		required_space
+	/// Revive an expired cursor. As its unread samples may have been overwritten, the cursor skips to the end of data.
+	#[cfg(feature = "cursor-timeout")]
+	pub fn revive_cursor(&mut self, cursor:&ReadCursor) {
+		self.cursors.cursors_mut()[cursor.0].expired = false;
+		self.skip_current_data(cursor);
 	}
 
 
@@ -93,19 +73,55 @@ impl<T:Default + Copy, const CAPACITY:usize, const MAX_READ_CURSOR_COUNT:usize>
 
 	/// Get all data that is written in the buffer, including the amount already having been read.
 	pub fn raw_data(&self, read_cursor:&ReadCursor) -> Vec<T> {
-		let mut output:Vec<T> = self.buffer.to_vec();
-		output.rotate_left(self.read_cursors[read_cursor.0]);
-		output
+		self.cursor_raw_data(read_cursor.0)
+	}
+
+	/// Get all unread samples for a specific cursor as two borrowed slices, without taking them. The second slice holds the samples after the wrap and is empty if the unread samples do not wrap.
+	pub fn as_slices(&self, cursor:&ReadCursor) -> [&[T]; 2] {
+		self.cursor_unread_slices(cursor.0)
+	}
+
+	/// Get at most the given amount of the oldest unread samples for a specific cursor as two borrowed slices, without taking them. Combined with consume, this allows reading without copying.
+	pub fn peek(&self, amount:usize, cursor:&ReadCursor) -> [&[T]; 2] {
+		self.cursor_peek(amount, cursor.0)
+	}
+
+	/// Mark an amount of unread samples as read for a specific cursor without copying them, for example after processing the slices from peek. Returns the amount of samples consumed.
+	pub fn consume(&mut self, amount:usize, cursor:&ReadCursor) -> usize {
+		self.cursor_consume(amount, cursor.0)
+	}
+
+	/// Create an iterator that takes all unread samples for a specific cursor one by one, oldest first, without allocating. Samples not iterated over are taken when the iterator is dropped.
+	pub fn drain(&mut self, cursor:&ReadCursor) -> Drain<'_, T, S, C> {
+		self.cursor_drain(cursor.0)
+	}
+
+	/// Drop the oldest unread samples for a specific cursor while the predicate returns true for them, without copying them out. Stops at the first sample for which the predicate returns false. Returns the amount of dropped samples.
+	/// Other cursors are not affected. The dropped samples count as skipped in the cursor statistics.
+	pub fn evict_while<F:FnMut(&T) -> bool>(&mut self, predicate:F, cursor:&ReadCursor) -> usize {
+		self.cursor_evict_while(predicate, cursor.0)
+	}
+
+	/// Get the minimum and maximum of the unread samples for a specific cursor in each of the given amount of buckets, without taking them. Allows rendering a large buffer to a limited amount of pixels.
+	/// If fewer samples than buckets are stored, each bucket holds a single sample.
+	pub fn downsample(&self, buckets:usize, cursor:&ReadCursor) -> Vec<(T, T)> where T:PartialOrd {
+		downsample_min_max(self.cursor_unread_slices(cursor.0), buckets)
+	}
+
+	/// Get the amount of unread samples for a specific cursor for which the predicate returns true, for unread samples sorted such that all samples matching the predicate come first. Runs in O(log n).
+	/// Combined with take, this allows skipping to the first sample after a timestamp or sequence number without checking every sample.
+	pub fn partition_point<F:FnMut(&T) -> bool>(&self, predicate:F, cursor:&ReadCursor) -> usize {
+		partition_point_split(self.cursor_unread_slices(cursor.0), predicate)
+	}
+
+	/// Binary search the sorted unread samples for a specific cursor using a comparator function, without taking them. Returns the index relative to the oldest unread sample, or the index where a matching sample could be inserted.
+	pub fn binary_search_by<F:FnMut(&T) -> Ordering>(&self, comparator:F, cursor:&ReadCursor) -> Result<usize, usize> {
+		binary_search_by_split(self.cursor_unread_slices(cursor.0), comparator)
 	}
 
 	/// Take one sample from the buffer.
-	pub fn take_one(&mut self, read_cursor:&ReadCursor) -> T {
-		let found:Vec<T> = self.take(1, read_cursor);
-		if found.is_empty() {
-			T::default()
-		} else {
-			found[0]
-		}
+	pub fn take_one(&mut self, read_cursor:&ReadCursor) -> T where T:Default {
+		self.cursor_take_one(read_cursor.0)
 	}
 
 	/// Take all remaining samples from the buffer.
@@ -115,51 +131,42 @@ impl<T:Default + Copy, const CAPACITY:usize, const MAX_READ_CURSOR_COUNT:usize>
 
 	/// Take an amount of samples from the buffer.
 	pub fn take(&mut self, amount:usize, read_cursor:&ReadCursor) -> Vec<T> {
-		let mut output_buffer:Vec<T> = vec![T::default(); amount];
-		let written_amount:usize = self.take_to_buffer(&mut output_buffer, read_cursor);
-		output_buffer[..written_amount].to_vec()
+		self.cursor_take(amount, read_cursor.0)
+	}
+
+	/// Take at most max samples from the buffer for a specific cursor, but only once at least min samples are stored. Returns None and takes nothing if less than min samples are stored. A max below min is raised to min.
+	pub fn take_at_least(&mut self, min:usize, max:usize, read_cursor:&ReadCursor) -> Option<Vec<T>> {
+		self.cursor_take_at_least(min, max, read_cursor.0)
+	}
+
+	/// Take exactly N samples from the buffer into an array for a specific cursor, without allocating. Returns None and takes nothing if less than N samples are stored.
+	pub fn take_array<const N:usize>(&mut self, read_cursor:&ReadCursor) -> Option<[T; N]> {
+		self.cursor_take_array(read_cursor.0)
 	}
 
 	/// Take an amount of samples from the buffer. Writes the data to the given output. Returns the amount of data taken from the buffer.
-	pub fn take_to_buffer(&mut self, output:&mut [T], read_cursor_ref:&ReadCursor) -> usize {
-		let mut read_cursor:usize = self.read_cursors[read_cursor_ref.0];
+	pub fn take_to_buffer(&mut self, output:&mut [T], read_cursor:&ReadCursor) -> usize {
+		self.cursor_take_to_buffer(output, read_cursor.0)
+	}
 
-		// Find out how much free space is left before wrap.
-		let used_space:usize = self.len(read_cursor_ref);
-		if used_space == 0 {
-			return 0;
-		}
-		let used_required_space:usize = used_space.min(output.len());
-
-		// Take straight part.
-		let straight_space:usize = used_required_space.min(CAPACITY - read_cursor);
-		output[..straight_space].copy_from_slice(&self.buffer[read_cursor..read_cursor + straight_space]);
-		read_cursor += straight_space;
-		let wrapped_space:usize = used_required_space - straight_space;
-		if wrapped_space != 0 {
-			read_cursor -= CAPACITY;
-			output[straight_space..straight_space + wrapped_space].copy_from_slice(&self.buffer[read_cursor..read_cursor + wrapped_space]);
-			read_cursor += wrapped_space;
-		}
-		
-		// Return taken amount.
-		self.read_cursors[read_cursor_ref.0] = read_cursor;
-		straight_space + wrapped_space
+	/// Take an amount of samples from the buffer. Writes the data to the given uninitialized output. Returns the amount of data taken from the buffer, which is the amount of samples at the start of the output that are initialized.
+	pub fn take_to_uninit(&mut self, output:&mut [MaybeUninit<T>], read_cursor:&ReadCursor) -> usize {
+		self.cursor_take_to_uninit(output, read_cursor.0)
+	}
+
+	/// Take a window of samples from the buffer, while only consuming the hop size, as used in STFT and FFT pipelines. Writes the window to the start of the output.
+	/// Returns false and takes nothing if less than the window or hop size is stored, or if the output is smaller than the window.
+	pub fn take_windowed(&mut self, window:usize, hop:usize, output:&mut [T], read_cursor:&ReadCursor) -> bool {
+		self.cursor_take_windowed(window, hop, output, read_cursor.0)
 	}
 
 
 
-	
 	/* PROPERTY GETTER METHODS */
 
 	/// Return the amount of unread samples stored for for a specific cursor.
 	pub fn len(&self, cursor:&ReadCursor) -> usize {
-		let read_cursor:usize = self.read_cursors[cursor.0];
-		if self.write_cursor >= read_cursor {
-			self.write_cursor - read_cursor
-		} else {
-			CAPACITY - (read_cursor - self.write_cursor)
-		}.min(CAPACITY)
+		self.cursor_len(cursor.0)
 	}
 
 	/// Wether or not there are 0 stored samples.
@@ -169,6 +176,73 @@ impl<T:Default + Copy, const CAPACITY:usize, const MAX_READ_CURSOR_COUNT:usize>
 
 	/// Wether or not the buffer is full.
 	pub fn is_full(&self, cursor:&ReadCursor) -> bool {
-		self.len(cursor) == CAPACITY - 1
+		self.len(cursor) == self.capacity - 1
+	}
+
+	/// Return the read sequence of a specific cursor, the total amount of samples ever stored to the buffer before the next sample this cursor will read.
+	pub fn read_sequence(&self, cursor:&ReadCursor) -> usize {
+		self.write_sequence - self.len(cursor)
+	}
+
+	/// Return the usage statistics of a specific cursor.
+	#[cfg(feature = "statistics")]
+	pub fn cursor_statistics(&self, cursor:&ReadCursor) -> &CursorStatistics {
+		&self.cursors.cursors()[cursor.0].statistics
+	}
+
+	/// Return the time of the last read using a specific cursor according to the clock of the buffer, if any.
+	/// Only kept track of while a cursor timeout is set, as the clock is not read otherwise.
+	#[cfg(feature = "cursor-timeout")]
+	pub fn cursor_read_time(&self, cursor:&ReadCursor) -> Option<Duration> {
+		self.cursors.cursors()[cursor.0].read_time
+	}
+
+	/// Return the time since the last read using a specific cursor, if any. Only kept track of while a cursor timeout is set.
+	#[cfg(feature = "cursor-timeout")]
+	pub fn cursor_idle_duration(&self, cursor:&ReadCursor) -> Option<Duration> {
+		self.cursor_read_time(cursor).map(|read_time| self.clock.0.now().saturating_sub(read_time))
+	}
+
+	/// Wether or not a cursor has expired after being inactive for longer than the cursor timeout.
+	#[cfg(feature = "cursor-timeout")]
+	pub fn is_cursor_expired(&self, cursor:&ReadCursor) -> bool {
+		self.cursors.cursors()[cursor.0].expired
+	}
+
+	/// Return the clock used for time-based features.
+	#[cfg(feature = "cursor-timeout")]
+	pub fn clock(&self) -> &'static dyn Clock {
+		self.clock.0
+	}
+
+	/// Return the inactivity timeout of cursors, if any.
+	#[cfg(feature = "cursor-timeout")]
+	pub fn cursor_timeout(&self) -> Option<Duration> {
+		self.cursor_timeout
+	}
+}
+impl<T:Copy, S:AsRef<[T]> + AsMut<[T]>, C:CursorPolicy<T> + MultiCursorPolicy> CursorBufferRead<T> for CircularBufferCore<T, S, C> {
+	fn len(&self, cursor:&ReadCursor) -> usize {
+		self.len(cursor)
+	}
+	fn take_to_buffer(&mut self, output:&mut [T], cursor:&ReadCursor) -> usize {
+		self.take_to_buffer(output, cursor)
+	}
+	fn peek_contiguous(&self, cursor:&ReadCursor) -> &[T] {
+		self.cursor_unread_slices(cursor.0)[0]
+	}
+	fn consume(&mut self, amount:usize, cursor:&ReadCursor) -> usize {
+		self.consume(amount, cursor)
+	}
+}
+#[cfg(feature = "dsp")]
+impl<T:Copy + WindowSample, S:AsRef<[T]> + AsMut<[T]>, C:CursorPolicy<T> + MultiCursorPolicy> CircularBufferCore<T, S, C> {
+
+	/* DSP METHODS */
+
+	/// Take a window of samples from the buffer multiplied by the window function, while only consuming the hop size. Writes the window to the start of the output.
+	/// Returns false and takes nothing if less than the window or hop size is stored, or if the output is smaller than the window.
+	pub fn take_windowed_with(&mut self, window:usize, hop:usize, output:&mut [T], function:WindowFunction, read_cursor:&ReadCursor) -> bool {
+		self.cursor_take_windowed_with(window, hop, output, function, read_cursor.0)
 	}
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
 	use crate::{ CircularBufferMultiRead, ReadCursor };
+	#[cfg(feature = "cursor-timeout")]
+	use crate::{ Clock, ManualClock };
 	use std::time::{ Duration, Instant };
 	
 	
@@ -150,6 +152,370 @@ mod tests {
 		assert!(!buffer.is_empty(&cursor));
 	}
 
+	#[test]
+	fn test_take_to_uninit() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		let cursor:ReadCursor = buffer.create_read_cursor();
+
+		buffer.extend(&[1, 2, 3, 4, 5, 6]);
+		buffer.take(4, &cursor);
+		buffer.extend(&[7, 8, 9]);
+
+		let mut output:Vec<i32> = Vec::with_capacity(10);
+		let taken:usize = buffer.take_to_uninit(output.spare_capacity_mut(), &cursor);
+		unsafe { output.set_len(taken); }
+		assert_eq!(output, vec![5, 6, 7, 8, 9]);
+		assert!(buffer.is_empty(&cursor));
+	}
+
+	#[test]
+	fn test_freeze_rejects_writes() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		let cursor:ReadCursor = buffer.create_read_cursor();
+
+		buffer.extend(&[1, 2, 3]);
+		buffer.freeze();
+		assert!(buffer.is_frozen());
+
+		// Writes are rejected, reads continue.
+		assert_eq!(buffer.extend(&[4, 5]), 0);
+		assert_eq!(buffer.push(6), 0);
+		assert_eq!(buffer.frozen_rejected_count(), 3);
+		assert_eq!(buffer.take_all(&cursor), vec![1, 2, 3]);
+
+		// Writes are accepted again after unfreezing.
+		buffer.unfreeze();
+		assert!(!buffer.is_frozen());
+		assert_eq!(buffer.extend(&[7, 8]), 2);
+		assert_eq!(buffer.take_all(&cursor), vec![7, 8]);
+		assert_eq!(buffer.frozen_rejected_count(), 3);
+	}
+
+	#[test]
+	fn test_write_and_read_sequence() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		let cursor:ReadCursor = buffer.create_read_cursor();
+		assert_eq!(buffer.write_sequence(), 0);
+
+		buffer.extend(&[1, 2, 3, 4, 5]);
+		let request_sequence:usize = buffer.write_sequence();
+		assert_eq!(request_sequence, 5);
+		assert!(buffer.is_sequence_visible(request_sequence));
+		assert!(!buffer.is_sequence_visible(request_sequence + 1));
+
+		// Read sequence follows the cursor, also after wrapping.
+		buffer.take(4, &cursor);
+		assert_eq!(buffer.read_sequence(&cursor), 4);
+		buffer.extend(&[6, 7, 8, 9, 10, 11, 12]);
+		assert_eq!(buffer.write_sequence(), 11);
+		assert_eq!(buffer.read_sequence(&cursor), 4);
+		buffer.take_all(&cursor);
+		assert_eq!(buffer.read_sequence(&cursor), 11);
+		assert!(buffer.is_sequence_visible(11));
+	}
+
+	#[test]
+	#[cfg(feature = "statistics")]
+	fn test_statistics_and_capacity_suggestion() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+
+		// The cursor furthest behind determines the occupancy.
+		buffer.extend(&[1, 2, 3, 4, 5]);
+		buffer.take(5, &cursor_a);
+		buffer.extend(&[6, 7, 8]);
+		buffer.take(8, &cursor_b);
+
+		assert_eq!(buffer.statistics().offered_count(), 8);
+		assert_eq!(buffer.statistics().written_count(), 7);
+		assert_eq!(buffer.statistics().dropped_count(), 1);
+		assert_eq!(buffer.statistics().high_water_mark(), TEST_CAPACITY - 1);
+		assert_eq!(buffer.statistics().suggest_capacity(0.0), 16);
+
+		buffer.reset_statistics();
+		assert_eq!(buffer.statistics().offered_count(), 0);
+	}
+
+	#[test]
+	fn test_take_windowed() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		let mut window:[i32; 4] = [0; 4];
+		buffer.extend(&[1, 2, 3, 4, 5, 6]);
+
+		// Overlapping windows.
+		assert!(buffer.take_windowed(4, 2, &mut window, &cursor_a));
+		assert_eq!(window, [1, 2, 3, 4]);
+		assert!(buffer.take_windowed(4, 2, &mut window, &cursor_a));
+		assert_eq!(window, [3, 4, 5, 6]);
+		assert_eq!(buffer.len(&cursor_a), 2);
+		assert_eq!(buffer.len(&cursor_b), 6);
+
+		// Not enough data for a full window.
+		assert!(!buffer.take_windowed(4, 2, &mut window, &cursor_a));
+		assert_eq!(buffer.len(&cursor_a), 2);
+
+		// Windows over the wrap.
+		buffer.take_all(&cursor_b);
+		buffer.extend(&[7, 8, 9]);
+		assert!(buffer.take_windowed(4, 3, &mut window, &cursor_a));
+		assert_eq!(window, [5, 6, 7, 8]);
+		assert_eq!(buffer.take_all(&cursor_a), vec![8, 9]);
+	}
+
+	#[cfg(feature = "dsp")]
+	#[test]
+	fn test_take_windowed_with_function() {
+		let mut buffer:CircularBufferMultiRead<f64, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = CircularBufferMultiRead::new();
+		let cursor:ReadCursor = buffer.create_read_cursor();
+		let mut window:[f64; 5] = [0.0; 5];
+		buffer.extend(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+		buffer.take(4, &cursor);
+		buffer.extend(&[7.0, 8.0, 9.0]);
+
+		// Window over the wrap.
+		assert!(buffer.take_windowed_with(5, 2, &mut window, crate::WindowFunction::Hann, &cursor));
+		assert!(window.iter().zip([0.0, 3.0, 7.0, 4.0, 0.0]).all(|(found, expected)| (found - expected).abs() < 1e-9));
+		assert_eq!(buffer.take_all(&cursor), vec![7.0, 8.0, 9.0]);
+		assert!(!buffer.take_windowed_with(5, 2, &mut window, crate::WindowFunction::Hann, &cursor));
+	}
+
+	#[test]
+	fn test_downsample() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		buffer.extend(&[1, 2, 3, 4, 5, 6]);
+		buffer.take(4, &cursor_a);
+		buffer.take(4, &cursor_b);
+		buffer.extend(&[9, -3, 7]);
+		buffer.take(1, &cursor_b);
+
+		assert_eq!(buffer.downsample(2, &cursor_a), vec![(5, 6), (-3, 9)]);
+		assert_eq!(buffer.downsample(2, &cursor_b), vec![(6, 9), (-3, 7)]);
+		assert_eq!(buffer.len(&cursor_a), 5);
+	}
+
+	#[test]
+	fn test_push_overwrite() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		for value in 0..TEST_CAPACITY as i32 - 1 {
+			assert_eq!(buffer.push_overwrite(value), None);
+		}
+		assert_eq!(buffer.take(3, &cursor_b), vec![0, 1, 2]);
+		assert_eq!(buffer.push_overwrite(100), Some(0));
+		assert_eq!(buffer.push_overwrite(101), Some(1));
+		assert_eq!(buffer.take_all(&cursor_a), vec![2, 3, 4, 5, 6, 100, 101]);
+		assert_eq!(buffer.take_all(&cursor_b), vec![3, 4, 5, 6, 100, 101]);
+	}
+
+	#[test]
+	fn test_sorted_search() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		buffer.extend(&[0, 0, 0, 0, 0]);
+		buffer.take(5, &cursor_a);
+		buffer.take(5, &cursor_b);
+		buffer.extend(&[10, 20, 30, 40, 50, 60]);
+		buffer.take(1, &cursor_b);
+
+		assert_eq!(buffer.partition_point(|sample| *sample <= 30, &cursor_a), 3);
+		assert_eq!(buffer.partition_point(|sample| *sample <= 30, &cursor_b), 2);
+		assert_eq!(buffer.binary_search_by(|sample| sample.cmp(&50), &cursor_a), Ok(4));
+		assert_eq!(buffer.binary_search_by(|sample| sample.cmp(&15), &cursor_b), Err(0));
+	}
+
+	#[test]
+	fn test_take_array() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		buffer.extend(&[1, 2, 3, 4, 5]);
+		buffer.take(3, &cursor_a);
+		buffer.take(3, &cursor_b);
+		buffer.extend(&[6, 7, 8, 9]);
+
+		assert_eq!(buffer.take_array::<4>(&cursor_a), Some([4, 5, 6, 7]));
+		assert_eq!(buffer.take_array::<3>(&cursor_a), None);
+		assert_eq!(buffer.take_array::<2>(&cursor_a), Some([8, 9]));
+		assert_eq!(buffer.take_array::<6>(&cursor_b), Some([4, 5, 6, 7, 8, 9]));
+	}
+
+	#[test]
+	fn test_push_array() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		assert!(buffer.push_array([1, 2, 3, 4]));
+		buffer.take_all(&cursor_a);
+		assert!(!buffer.push_array([5, 6, 7, 8]));
+		assert!(buffer.push_array([5, 6, 7]));
+		assert_eq!(buffer.take_array::<3>(&cursor_a), Some([5, 6, 7]));
+		assert_eq!(buffer.take_array::<7>(&cursor_b), Some([1, 2, 3, 4, 5, 6, 7]));
+	}
+
+	#[test]
+	fn test_take_at_least() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		buffer.extend(&[1, 2]);
+		assert_eq!(buffer.take_at_least(3, 4, &cursor_a), None);
+		buffer.extend(&[3, 4, 5]);
+		assert_eq!(buffer.take_at_least(3, 4, &cursor_a), Some(vec![1, 2, 3, 4]));
+		assert_eq!(buffer.take_at_least(3, 4, &cursor_a), None);
+		assert_eq!(buffer.take_at_least(5, 8, &cursor_b), Some(vec![1, 2, 3, 4, 5]));
+	}
+
+	#[test]
+	#[cfg(feature = "cursor-timeout")]
+	fn test_cursor_timeout_expires_abandoned_cursor() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		let cursor_active:ReadCursor = buffer.create_read_cursor();
+		let cursor_abandoned:ReadCursor = buffer.create_read_cursor();
+		buffer.set_cursor_timeout(Some(Duration::from_millis(20)));
+		buffer.extend(&[1, 2, 3, 4, 5, 6, 7]);
+		assert_eq!(buffer.take_all(&cursor_active), vec![1, 2, 3, 4, 5, 6, 7]);
+		assert_eq!(buffer.push(8), 0);
+		assert!(!buffer.is_cursor_expired(&cursor_abandoned));
+
+		std::thread::sleep(Duration::from_millis(40));
+		buffer.take_all(&cursor_active);
+		assert_eq!(buffer.extend(&[8, 9]), 2);
+		assert!(!buffer.is_cursor_expired(&cursor_active));
+		assert!(buffer.is_cursor_expired(&cursor_abandoned));
+		assert!(buffer.is_empty(&cursor_abandoned));
+		assert_eq!(buffer.take_all(&cursor_active), vec![8, 9]);
+
+		buffer.revive_cursor(&cursor_abandoned);
+		assert!(!buffer.is_cursor_expired(&cursor_abandoned));
+		buffer.push(10);
+		assert_eq!(buffer.take_all(&cursor_abandoned), vec![10]);
+	}
+
+	#[test]
+	#[cfg(feature = "cursor-timeout")]
+	fn test_cursor_timeout_with_manual_clock() {
+		static CLOCK:ManualClock = ManualClock::new();
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		buffer.set_clock(&CLOCK);
+		let cursor_active:ReadCursor = buffer.create_read_cursor();
+		let cursor_abandoned:ReadCursor = buffer.create_read_cursor();
+		buffer.set_cursor_timeout(Some(Duration::from_secs(10)));
+
+		CLOCK.advance(Duration::from_secs(6));
+		buffer.take_all(&cursor_active);
+		buffer.push(1);
+		assert!(!buffer.is_cursor_expired(&cursor_abandoned));
+
+		CLOCK.advance(Duration::from_secs(6));
+		buffer.push(2);
+		assert!(!buffer.is_cursor_expired(&cursor_active));
+		assert!(buffer.is_cursor_expired(&cursor_abandoned));
+		assert_eq!(buffer.cursor_idle_duration(&cursor_abandoned), Some(Duration::from_secs(12)));
+	}
+
+	#[test]
+	#[cfg(feature = "cursor-timeout")]
+	fn test_clock_only_read_with_cursor_timeout() {
+		use std::sync::atomic::{ AtomicUsize, Ordering };
+
+		struct CountingClock(AtomicUsize);
+		impl Clock for CountingClock {
+			fn now(&self) -> Duration {
+				self.0.fetch_add(1, Ordering::Relaxed);
+				Duration::ZERO
+			}
+		}
+		static CLOCK:CountingClock = CountingClock(AtomicUsize::new(0));
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		buffer.set_clock(&CLOCK);
+		let cursor:ReadCursor = buffer.create_read_cursor();
+		buffer.extend(&[1, 2, 3, 4]);
+		buffer.take(2, &cursor);
+		buffer.take_all(&cursor);
+		assert_eq!(CLOCK.0.load(Ordering::Relaxed), 0);
+		assert_eq!(buffer.cursor_read_time(&cursor), None);
+
+		buffer.set_cursor_timeout(Some(Duration::from_secs(10)));
+		buffer.take_all(&cursor);
+		assert!(CLOCK.0.load(Ordering::Relaxed) > 0);
+		assert_eq!(buffer.cursor_read_time(&cursor), Some(Duration::ZERO));
+	}
+
+	#[test]
+	#[cfg(feature = "statistics")]
+	fn test_cursor_statistics() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		buffer.extend(&[1, 2, 3, 4, 5, 6, 7]);
+		buffer.take(3, &cursor_a);
+		let mut window:[i32; 4] = [0; 4];
+		assert!(buffer.take_windowed(4, 2, &mut window, &cursor_a));
+		buffer.push_overwrite(8);
+		buffer.skip_current_data(&cursor_b);
+
+		assert_eq!(buffer.cursor_statistics(&cursor_a).taken_count(), 5);
+		assert_eq!(buffer.cursor_statistics(&cursor_a).skipped_count(), 0);
+		assert_eq!(buffer.cursor_statistics(&cursor_b).taken_count(), 0);
+		assert_eq!(buffer.cursor_statistics(&cursor_b).skipped_count(), 8);
+	}
+
+	#[test]
+	fn test_zero_sized_samples() {
+		let mut buffer:CircularBufferMultiRead<(), 8, 2> = CircularBufferMultiRead::new();
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		for cycle in 0..20 {
+			assert_eq!(buffer.extend(&[(); 5]), 5);
+			assert_eq!(buffer.take(3, &cursor_a).len(), 3);
+			assert_eq!(buffer.take_array::<2>(&cursor_a), Some([(); 2]));
+			assert_eq!(buffer.take_all(&cursor_b).len(), 5);
+			assert!(buffer.is_empty(&cursor_a) && buffer.is_empty(&cursor_b), "Failed at cycle {cycle}");
+		}
+		assert_eq!(buffer.extend(&[(); 100]), 7);
+		assert_eq!(buffer.take_all(&cursor_a).len(), 7);
+		assert_eq!(buffer.extend(&[(); 3]), 0);
+		assert_eq!(buffer.write_sequence(), 107);
+	}
+
+	#[test]
+	fn test_evict_while() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		buffer.extend(&[1, 2, 3, 4, 5]);
+		buffer.take(4, &cursor_a);
+		buffer.take(4, &cursor_b);
+		buffer.extend(&[6, 7, 8, 9]);
+		assert_eq!(buffer.evict_while(|sample| *sample < 8, &cursor_a), 3);
+		assert_eq!(buffer.take_all(&cursor_a), vec![8, 9]);
+		assert_eq!(buffer.take_all(&cursor_b), vec![5, 6, 7, 8, 9]);
+		#[cfg(feature = "statistics")]
+		assert_eq!(buffer.cursor_statistics(&cursor_a).skipped_count(), 3);
+	}
+
+	#[test]
+	fn test_last_written() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = get_test_buffer();
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		assert_eq!(buffer.last_written(), None);
+		buffer.extend(&[1, 2, 3, 4, 5, 6]);
+		buffer.take_all(&cursor_a);
+		buffer.take(2, &cursor_b);
+		buffer.extend(&[7, 8]);
+		assert_eq!(buffer.last_written(), Some(&8));
+		assert_eq!(buffer.take_all(&cursor_a), vec![7, 8]);
+		assert_eq!(buffer.take_all(&cursor_b), vec![3, 4, 5, 6, 7, 8]);
+	}
+
 	#[test]
 	fn test_brute_force_cursors() {
 		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = CircularBufferMultiRead::new();
@@ -217,7 +583,7 @@ mod tests {
 		let cursor_b:ReadCursor = buffer.create_read_cursor();
 
 		assert_eq!(buffer.take(10, &cursor_a), vec![2, 3]);
-		assert_eq!(buffer.take(10, &cursor_b), vec![]);
+		assert_eq!(buffer.take(10, &cursor_b), Vec::<i32>::new());
 		assert!(buffer.is_empty(&cursor_a));
 		assert!(buffer.is_empty(&cursor_b));
 	}
@@ -345,7 +711,7 @@ mod tests {
 
 		let mut counter:i32 = 0;
 		for _ in 0..LOOPS {
-			let data:Vec<i32> = (0..512).map(|x| counter + x as i32).collect();
+			let data:Vec<i32> = (0..512).map(|x| counter + x).collect();
 			buffer.extend(&data);
 			counter += 512;
 
@@ -384,7 +750,7 @@ mod tests {
 		let cursors:Vec<ReadCursor> = (0..8).map(|_| buffer.create_read_cursor()).collect();
 		
 		// Write semi-randomized batch sizes of data.
-		let write_data:Vec<i32> = (0..1000).map(|index| index).collect();
+		let write_data:Vec<i32> = (0..1000).collect();
 		let mut read_data:[Vec<i32>; 8] = [Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new()];
 		let mut write_data_cursor:usize = 0;
 		for write_batch_size in [60, 40, 160, 140, 100, 200, 180, 120] {
@@ -448,4 +814,23 @@ mod tests {
 			buffer.skip_current_data(&cursor_b);
 		}
 	}
+
+	#[test]
+	fn test_zero_copy_reads() {
+		let mut buffer:CircularBufferMultiRead<i32, TEST_CAPACITY, TEST_MAX_CURSOR_COUNT> = CircularBufferMultiRead::new();
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		buffer.extend(&[0, 1, 2, 3, 4, 5]);
+		buffer.take(6, &cursor_a);
+		buffer.take(6, &cursor_b);
+		buffer.extend(&[6, 7, 8, 9]);
+
+		assert_eq!(buffer.as_slices(&cursor_a), [&[6, 7][..], &[8, 9][..]]);
+		assert_eq!(buffer.peek(3, &cursor_a), [&[6, 7][..], &[8][..]]);
+		assert_eq!(buffer.consume(3, &cursor_a), 3);
+		assert_eq!(buffer.drain(&cursor_a).collect::<Vec<i32>>(), vec![9]);
+		assert!(buffer.is_empty(&cursor_a));
+		assert_eq!(buffer.drain(&cursor_b).len(), 4);
+		assert!(buffer.is_empty(&cursor_b));
+	}
 }
\ No newline at end of file
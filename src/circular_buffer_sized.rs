@@ -0,0 +1,176 @@
+use std::collections::{ VecDeque, vec_deque::Drain };
+
+
+
+/// Returns the size of a sample in bytes, for samples with a variable size.
+pub trait ByteSize {
+
+	/// Return the size of the sample in bytes.
+	fn byte_size(&self) -> usize;
+}
+impl ByteSize for Vec<u8> {
+	fn byte_size(&self) -> usize {
+		self.len()
+	}
+}
+impl ByteSize for Box<[u8]> {
+	fn byte_size(&self) -> usize {
+		self.len()
+	}
+}
+impl ByteSize for String {
+	fn byte_size(&self) -> usize {
+		self.len()
+	}
+}
+
+
+
+/// Works the same as CircularBufferDyn, but has a capacity in a total size instead of an amount of samples.
+/// The size of each sample is determined by a size function, which allows samples with a variable size, like lines in a log, to be stored with a predictable memory bound.
+/// Every sample counts as at least a size of 1, so samples with a size of 0, like empty strings, can not be stored without bound.
+#[derive(Clone)]
+pub struct CircularBufferSized<T> {
+	buffer:VecDeque<T>,
+	size_function:fn(&T) -> usize,
+	size_capacity:usize,
+	size:usize
+}
+impl<T:ByteSize> CircularBufferSized<T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new circular-buffer that can store samples up to the given total amount of bytes.
+	pub fn new_byte_sized(byte_capacity:usize) -> CircularBufferSized<T> {
+		CircularBufferSized::new(byte_capacity, <T as ByteSize>::byte_size)
+	}
+}
+impl<T> CircularBufferSized<T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new circular-buffer that can store samples up to the given total size, using the size function to determine the size of each sample.
+	pub fn new(size_capacity:usize, size_function:fn(&T) -> usize) -> CircularBufferSized<T> {
+		CircularBufferSized {
+			buffer: VecDeque::new(),
+			size_function,
+			size_capacity,
+			size: 0
+		}
+	}
+
+
+
+	/* BUFFER METHODS */
+
+	/// Add a single sample to the buffer. Returns the amount of samples stored to the buffer.
+	pub fn push(&mut self, input:T) -> usize {
+		let sample_size:usize = self.charged_size(&input);
+		if self.size + sample_size > self.size_capacity {
+			return 0;
+		}
+		self.size += sample_size;
+		self.buffer.push_back(input);
+		1
+	}
+
+	/// Add a single sample to the buffer, taking the oldest samples until it fits. Returns the taken samples, or None if the sample is larger than the capacity and was not stored.
+	pub fn push_evicting(&mut self, input:T) -> Option<Vec<T>> {
+		let sample_size:usize = self.charged_size(&input);
+		if sample_size > self.size_capacity {
+			return None;
+		}
+		let mut evicted:Vec<T> = Vec::new();
+		while self.size + sample_size > self.size_capacity {
+			evicted.extend(self.take_one());
+		}
+		self.push(input);
+		Some(evicted)
+	}
+
+	/// Add a list of samples to the buffer. Returns the amount of samples stored to the buffer.
+	/// If a sample does not fit, it and all samples after it are not stored.
+	pub fn extend<I:IntoIterator<Item = T>>(&mut self, input:I) -> usize {
+		let mut written:usize = 0;
+		for sample in input {
+			if self.push(sample) == 0 {
+				break;
+			}
+			written += 1;
+		}
+		written
+	}
+
+	/// Take one sample from the buffer.
+	pub fn take_one(&mut self) -> Option<T> {
+		let sample:T = self.buffer.pop_front()?;
+		self.size -= self.charged_size(&sample);
+		Some(sample)
+	}
+
+	/// Take all remaining samples from the buffer.
+	pub fn take_all(&mut self) -> Vec<T> {
+		self.take(self.len())
+	}
+
+	/// Take an amount of samples from the buffer.
+	pub fn take(&mut self, amount:usize) -> Vec<T> {
+		(0..amount.min(self.len())).filter_map(|_| self.take_one()).collect()
+	}
+
+	/// Create an iterator that takes all stored samples, oldest first. Samples not iterated over are taken when the iterator is dropped.
+	pub fn drain(&mut self) -> Drain<'_, T> {
+		self.size = 0;
+		self.buffer.drain(..)
+	}
+
+	/// Get all stored samples as two borrowed slices, without taking them, oldest first.
+	pub fn as_slices(&self) -> [&[T]; 2] {
+		let (first, second) = self.buffer.as_slices();
+		[first, second]
+	}
+
+	/// Return the size a sample counts towards the size capacity, which is at least 1.
+	fn charged_size(&self, sample:&T) -> usize {
+		(self.size_function)(sample).max(1)
+	}
+
+	/// Iterate over all stored samples without taking them, oldest first.
+	pub fn iter(&self) -> impl Iterator<Item = &T> {
+		self.buffer.iter()
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Return the amount of currently stored samples.
+	pub fn len(&self) -> usize {
+		self.buffer.len()
+	}
+
+	/// Wether or not there are 0 stored samples.
+	pub fn is_empty(&self) -> bool {
+		self.buffer.is_empty()
+	}
+
+	/// Return the total size of all currently stored samples, counting every sample as at least a size of 1.
+	pub fn size(&self) -> usize {
+		self.size
+	}
+
+	/// Return the maximum total size of all stored samples.
+	pub fn size_capacity(&self) -> usize {
+		self.size_capacity
+	}
+
+	/// Return the total size that can still be stored.
+	pub fn available_size(&self) -> usize {
+		self.size_capacity - self.size
+	}
+
+	/// Return the total amount of bytes held by the buffer, assuming the size function returns the amount of bytes each sample holds outside of itself, like the content of a String.
+	pub fn memory_usage(&self) -> usize {
+		size_of::<CircularBufferSized<T>>() + self.buffer.capacity() * size_of::<T>() + self.size
+	}
+}
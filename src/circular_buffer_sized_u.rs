@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+	use crate::CircularBufferSized;
+
+
+
+	const TEST_BYTE_CAPACITY:usize = 16;
+	fn get_test_buffer() -> CircularBufferSized<String> {
+		CircularBufferSized::new_byte_sized(TEST_BYTE_CAPACITY)
+	}
+
+
+
+	#[test]
+	fn test_new_buffer_is_empty() {
+		let buffer:CircularBufferSized<String> = get_test_buffer();
+		assert_eq!(buffer.len(), 0);
+		assert_eq!(buffer.size(), 0);
+		assert!(buffer.is_empty());
+		assert_eq!(buffer.size_capacity(), TEST_BYTE_CAPACITY);
+	}
+
+	#[test]
+	fn test_push_and_take() {
+		let mut buffer:CircularBufferSized<String> = get_test_buffer();
+		assert_eq!(buffer.push(String::from("hello")), 1);
+		assert_eq!(buffer.push(String::from("world")), 1);
+		assert_eq!(buffer.size(), 10);
+		assert_eq!(buffer.available_size(), 6);
+
+		assert_eq!(buffer.take_one(), Some(String::from("hello")));
+		assert_eq!(buffer.size(), 5);
+		assert_eq!(buffer.take_all(), vec![String::from("world")]);
+		assert_eq!(buffer.take_one(), None);
+		assert_eq!(buffer.size(), 0);
+	}
+
+	#[test]
+	fn test_reject_over_budget() {
+		let mut buffer:CircularBufferSized<String> = get_test_buffer();
+		assert_eq!(buffer.push(String::from("0123456789")), 1);
+		assert_eq!(buffer.push(String::from("0123456789")), 0);
+		assert_eq!(buffer.push(String::from("012345")), 1);
+		assert_eq!(buffer.size(), TEST_BYTE_CAPACITY);
+		assert_eq!(buffer.push(String::new()), 0);
+		assert_eq!(buffer.len(), 2);
+	}
+
+	#[test]
+	fn test_extend_truncates() {
+		let mut buffer:CircularBufferSized<String> = get_test_buffer();
+		let lines:Vec<String> = ["aaaa", "bbbbbbbb", "cccccc", "d"].iter().map(|line| line.to_string()).collect();
+		assert_eq!(buffer.extend(lines), 2);
+		assert_eq!(buffer.iter().collect::<Vec<&String>>(), vec!["aaaa", "bbbbbbbb"]);
+		assert_eq!(buffer.take(1), vec![String::from("aaaa")]);
+	}
+
+	#[test]
+	fn test_push_evicting() {
+		let mut buffer:CircularBufferSized<String> = get_test_buffer();
+		assert_eq!(buffer.push_evicting(String::from("aaaaaa")), Some(vec![]));
+		assert_eq!(buffer.push_evicting(String::from("bbbbbb")), Some(vec![]));
+		assert_eq!(buffer.push_evicting(String::from("cccc")), Some(vec![]));
+		assert_eq!(buffer.push_evicting(String::from("dddddddddd")), Some(vec![String::from("aaaaaa"), String::from("bbbbbb")]));
+		assert_eq!(buffer.push_evicting(String::from("01234567890123456")), None);
+		assert_eq!(buffer.take_all(), vec![String::from("cccc"), String::from("dddddddddd")]);
+	}
+
+	#[test]
+	fn test_custom_size_function() {
+		let mut buffer:CircularBufferSized<Vec<u32>> = CircularBufferSized::new(16, |samples| samples.len() * size_of::<u32>());
+		assert_eq!(buffer.push(vec![1, 2, 3]), 1);
+		assert_eq!(buffer.push(vec![4, 5]), 0);
+		assert_eq!(buffer.push(vec![6]), 1);
+		assert_eq!(buffer.size(), 16);
+		assert_eq!(buffer.take_all(), vec![vec![1, 2, 3], vec![6]]);
+	}
+
+	#[test]
+	fn test_memory_usage() {
+		let mut buffer:CircularBufferSized<String> = get_test_buffer();
+		let empty_usage:usize = buffer.memory_usage();
+		buffer.push(String::from("aaaa"));
+		assert!(buffer.memory_usage() >= empty_usage + 4);
+		assert!(buffer.memory_usage() >= size_of::<CircularBufferSized<String>>() + size_of::<String>() + 4);
+	}
+
+	#[test]
+	fn test_drain_and_slices() {
+		let mut buffer:CircularBufferSized<String> = get_test_buffer();
+		buffer.extend([String::from("ab"), String::from("cd")]);
+		let [first, second] = buffer.as_slices();
+		assert_eq!(first.len() + second.len(), 2);
+		assert_eq!(buffer.drain().collect::<Vec<String>>(), vec![String::from("ab"), String::from("cd")]);
+		assert!(buffer.is_empty());
+		assert_eq!(buffer.size(), 0);
+	}
+
+	#[test]
+	fn test_zero_sized_samples_are_bounded() {
+		let mut buffer:CircularBufferSized<i32> = CircularBufferSized::new(4, |_| 0);
+		assert_eq!(buffer.extend(0..10), 4);
+		assert_eq!(buffer.len(), 4);
+		assert_eq!(buffer.size(), 4);
+		assert_eq!(buffer.push_evicting(10), Some(vec![0]));
+		assert_eq!(buffer.take_all(), vec![1, 2, 3, 10]);
+		assert_eq!(buffer.size(), 0);
+	}
+}
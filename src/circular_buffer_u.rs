@@ -2,6 +2,7 @@
 mod tests {
 	use std::time::{ Duration, Instant };
 	use crate::CircularBuffer;
+	use std::io::Write;
 	
 	
 
@@ -131,6 +132,272 @@ mod tests {
 		assert!(!buffer.is_empty());
 	}
 
+	#[test]
+	fn test_take_to_uninit() {
+		let mut buffer:CircularBuffer<i32, TEST_CAPACITY> = get_test_buffer();
+		buffer.extend(&[1, 2, 3, 4, 5, 6]);
+		buffer.take(4);
+		buffer.extend(&[7, 8, 9]);
+
+		let mut output:Vec<i32> = Vec::with_capacity(10);
+		let taken:usize = buffer.take_to_uninit(output.spare_capacity_mut());
+		unsafe { output.set_len(taken); }
+		assert_eq!(output, vec![5, 6, 7, 8, 9]);
+		assert!(buffer.is_empty());
+	}
+
+	#[test]
+	fn test_freeze_rejects_writes() {
+		let mut buffer:CircularBuffer<i32, TEST_CAPACITY> = get_test_buffer();
+		buffer.extend(&[1, 2, 3]);
+		buffer.freeze();
+		assert!(buffer.is_frozen());
+
+		// Writes are rejected, reads continue.
+		assert_eq!(buffer.extend(&[4, 5]), 0);
+		assert_eq!(buffer.push(6), 0);
+		assert_eq!(buffer.frozen_rejected_count(), 3);
+		assert_eq!(buffer.take_all(), vec![1, 2, 3]);
+
+		// Writes are accepted again after unfreezing.
+		buffer.unfreeze();
+		assert!(!buffer.is_frozen());
+		assert_eq!(buffer.extend(&[7, 8]), 2);
+		assert_eq!(buffer.take_all(), vec![7, 8]);
+		assert_eq!(buffer.frozen_rejected_count(), 3);
+	}
+
+	#[test]
+	#[cfg(feature = "statistics")]
+	fn test_statistics_and_capacity_suggestion() {
+		let mut buffer:CircularBuffer<i32, TEST_CAPACITY> = get_test_buffer();
+		buffer.extend(&[1, 2, 3, 4, 5]);
+		buffer.take(5);
+		buffer.extend(&(0..12).collect::<Vec<i32>>());
+
+		assert_eq!(buffer.statistics().offered_count(), 17);
+		assert_eq!(buffer.statistics().written_count(), 12);
+		assert_eq!(buffer.statistics().dropped_count(), 5);
+		assert_eq!(buffer.statistics().high_water_mark(), TEST_CAPACITY - 1);
+		assert_eq!(buffer.statistics().suggest_capacity(0.0), 16);
+
+		// Frozen rejections are not counted as dropped.
+		buffer.freeze();
+		buffer.extend(&[1, 2, 3]);
+		assert_eq!(buffer.statistics().dropped_count(), 5);
+
+		buffer.reset_statistics();
+		assert_eq!(buffer.statistics().offered_count(), 0);
+	}
+
+	#[test]
+	fn test_take_windowed() {
+		let mut buffer:CircularBuffer<i32, TEST_CAPACITY> = get_test_buffer();
+		let mut window:[i32; 4] = [0; 4];
+		buffer.extend(&[1, 2, 3, 4, 5, 6]);
+
+		// Overlapping windows.
+		assert!(buffer.take_windowed(4, 2, &mut window));
+		assert_eq!(window, [1, 2, 3, 4]);
+		assert!(buffer.take_windowed(4, 2, &mut window));
+		assert_eq!(window, [3, 4, 5, 6]);
+		assert_eq!(buffer.len(), 2);
+
+		// Not enough data for a full window.
+		assert!(!buffer.take_windowed(4, 2, &mut window));
+		assert_eq!(buffer.len(), 2);
+
+		// Windows over the wrap.
+		buffer.extend(&[7, 8, 9]);
+		assert!(buffer.take_windowed(4, 3, &mut window));
+		assert_eq!(window, [5, 6, 7, 8]);
+		assert_eq!(buffer.take_all(), vec![8, 9]);
+
+		// Output too small.
+		buffer.extend(&[1, 2, 3, 4, 5]);
+		assert!(!buffer.take_windowed(5, 1, &mut window));
+	}
+
+	#[cfg(feature = "dsp")]
+	#[test]
+	fn test_take_windowed_with_function() {
+		let mut buffer:CircularBuffer<f64, TEST_CAPACITY> = CircularBuffer::new();
+		let mut window:[f64; 5] = [0.0; 5];
+		buffer.extend(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+		buffer.take(4);
+		buffer.extend(&[7.0, 8.0, 9.0]);
+
+		// Window over the wrap.
+		assert!(buffer.take_windowed_with(5, 2, &mut window, crate::WindowFunction::Hann));
+		assert!(window.iter().zip([0.0, 3.0, 7.0, 4.0, 0.0]).all(|(found, expected)| (found - expected).abs() < 1e-9));
+		assert_eq!(buffer.take_all(), vec![7.0, 8.0, 9.0]);
+		assert!(!buffer.take_windowed_with(5, 2, &mut window, crate::WindowFunction::Hann));
+	}
+
+	#[test]
+	fn test_downsample() {
+		let mut buffer:CircularBuffer<i32, TEST_CAPACITY> = get_test_buffer();
+		buffer.extend(&[1, 2, 3, 4, 5, 6]);
+		buffer.take(4);
+		buffer.extend(&[9, -3, 7]);
+
+		assert_eq!(buffer.downsample(2), vec![(5, 6), (-3, 9)]);
+		assert_eq!(buffer.downsample(10), vec![(5, 5), (6, 6), (9, 9), (-3, -3), (7, 7)]);
+		assert_eq!(buffer.len(), 5);
+	}
+
+	#[test]
+	fn test_push_overwrite() {
+		let mut buffer:CircularBuffer<i32, TEST_CAPACITY> = get_test_buffer();
+		for value in 0..TEST_CAPACITY as i32 - 1 {
+			assert_eq!(buffer.push_overwrite(value), None);
+		}
+		assert_eq!(buffer.push_overwrite(100), Some(0));
+		assert_eq!(buffer.push_overwrite(101), Some(1));
+		assert_eq!(buffer.take_all(), vec![2, 3, 4, 5, 6, 100, 101]);
+
+		buffer.freeze();
+		assert_eq!(buffer.push_overwrite(102), None);
+		assert!(buffer.is_empty());
+	}
+
+	#[test]
+	fn test_sorted_search() {
+		let mut buffer:CircularBuffer<i32, TEST_CAPACITY> = get_test_buffer();
+		buffer.extend(&[0, 0, 0, 0, 0]);
+		buffer.take(5);
+		buffer.extend(&[10, 20, 30, 40, 50, 60]);
+
+		let skip_amount:usize = buffer.partition_point(|sample| *sample <= 30);
+		assert_eq!(skip_amount, 3);
+		assert_eq!(buffer.binary_search_by(|sample| sample.cmp(&50)), Ok(4));
+		assert_eq!(buffer.binary_search_by(|sample| sample.cmp(&15)), Err(1));
+		buffer.take(skip_amount);
+		assert_eq!(buffer.take_all(), vec![40, 50, 60]);
+	}
+
+	#[test]
+	fn test_take_array() {
+		let mut buffer:CircularBuffer<i32, TEST_CAPACITY> = get_test_buffer();
+		buffer.extend(&[1, 2, 3, 4, 5]);
+		buffer.take(3);
+		buffer.extend(&[6, 7, 8, 9]);
+
+		assert_eq!(buffer.take_array::<4>(), Some([4, 5, 6, 7]));
+		assert_eq!(buffer.take_array::<3>(), None);
+		assert_eq!(buffer.take_array::<2>(), Some([8, 9]));
+		assert_eq!(buffer.take_array::<0>(), Some([]));
+	}
+
+	#[test]
+	fn test_push_array() {
+		let mut buffer:CircularBuffer<i32, TEST_CAPACITY> = get_test_buffer();
+		assert!(buffer.push_array([1, 2, 3, 4]));
+		assert!(!buffer.push_array([5, 6, 7, 8]));
+		assert!(buffer.push_array([5, 6, 7]));
+		assert!(!buffer.push_array([8]));
+		#[cfg(feature = "statistics")]
+		assert_eq!(buffer.statistics().dropped_count(), 5);
+		assert_eq!(buffer.take_array::<7>(), Some([1, 2, 3, 4, 5, 6, 7]));
+
+		buffer.freeze();
+		assert!(!buffer.push_array([1]));
+		assert!(buffer.is_empty());
+	}
+
+	#[test]
+	fn test_new_with_fn() {
+		let mut buffer:CircularBuffer<i32, TEST_CAPACITY> = CircularBuffer::new_with_fn(|index| index as i32 * 10);
+		assert!(buffer.is_full());
+		assert_eq!(buffer.take(3), vec![0, 10, 20]);
+		buffer.extend(&[1, 2, 3]);
+		assert_eq!(buffer.take_all(), vec![30, 40, 50, 60, 1, 2, 3]);
+	}
+
+	#[test]
+	fn test_take_at_least() {
+		let mut buffer:CircularBuffer<i32, TEST_CAPACITY> = get_test_buffer();
+		buffer.extend(&[1, 2]);
+		assert_eq!(buffer.take_at_least(3, 4), None);
+		buffer.extend(&[3, 4, 5]);
+		assert_eq!(buffer.take_at_least(3, 4), Some(vec![1, 2, 3, 4]));
+		assert_eq!(buffer.take_at_least(1, 4), Some(vec![5]));
+		assert_eq!(buffer.take_at_least(0, 4), Some(vec![]));
+	}
+
+	#[test]
+	fn test_zero_sized_samples() {
+		let mut buffer:CircularBuffer<(), 8> = CircularBuffer::new();
+		for cycle in 0..20 {
+			assert_eq!(buffer.extend(&[(); 5]), 5);
+			assert_eq!(buffer.push(()), 1);
+			assert_eq!(buffer.len(), 6);
+			assert_eq!(buffer.take(4).len(), 4);
+			assert_eq!(buffer.take_array::<2>(), Some([(); 2]));
+			assert!(buffer.is_empty(), "Failed at cycle {cycle}");
+		}
+		assert_eq!(buffer.extend(&[(); 100]), 7);
+		assert!(buffer.is_full());
+		assert_eq!(buffer.push_overwrite(()), Some(()));
+		assert_eq!(buffer.take_all().len(), 7);
+		#[cfg(feature = "statistics")]
+		assert_eq!(buffer.statistics().dropped_count(), 93);
+	}
+
+	#[test]
+	fn test_evict_while() {
+		let mut buffer:CircularBuffer<i32, TEST_CAPACITY> = get_test_buffer();
+		buffer.extend(&[1, 2, 3, 4, 5]);
+		buffer.take(4);
+		buffer.extend(&[6, 7, 8, 9]);
+		assert_eq!(buffer.evict_while(|sample| *sample < 8), 3);
+		assert_eq!(buffer.evict_while(|sample| *sample > 100), 0);
+		assert_eq!(buffer.take_all(), vec![8, 9]);
+		assert_eq!(buffer.evict_while(|_| true), 0);
+	}
+
+	#[test]
+	fn test_last_written() {
+		let mut buffer:CircularBuffer<i32, TEST_CAPACITY> = get_test_buffer();
+		assert_eq!(buffer.last_written(), None);
+		buffer.extend(&[1, 2, 3, 4, 5, 6]);
+		buffer.take_all();
+		assert_eq!(buffer.last_written(), Some(&6));
+		buffer.extend(&[7, 8, 9]);
+		assert_eq!(buffer.last_written(), Some(&9));
+		assert_eq!(buffer.take_all(), vec![7, 8, 9]);
+
+		let primed:CircularBuffer<i32, 4> = CircularBuffer::new_with_fn(|index| index as i32);
+		assert_eq!(primed.last_written(), Some(&2));
+	}
+
+	#[cfg(feature = "cobs")]
+	#[test]
+	fn test_cobs_frames() {
+		let mut buffer:CircularBuffer<u8, 16> = CircularBuffer::new();
+		assert!(buffer.push_cobs_frame(&[0x11, 0x00, 0x22]));
+		assert!(buffer.push_cobs_frame(&[]));
+		assert!(!buffer.push_cobs_frame(&[0x33; 32]));
+		assert_eq!(buffer.take_cobs_frame(), Some(Ok(vec![0x11, 0x00, 0x22])));
+		assert_eq!(buffer.take_cobs_frame(), Some(Ok(vec![])));
+		assert_eq!(buffer.take_cobs_frame(), None);
+
+		// Raw bytes arriving one by one, with a damaged frame and extra delimiters in between.
+		for byte in [0x00, 0x05, 0x11, 0x00, 0x00, 0x02, 0x44] {
+			buffer.push(byte);
+		}
+		assert_eq!(buffer.take_cobs_frame(), Some(Err(crate::InvalidCobsFrame(2))));
+		assert_eq!(buffer.take_cobs_frame(), None);
+		buffer.push(0x00);
+		assert_eq!(buffer.take_cobs_frame(), Some(Ok(vec![0x44])));
+		assert!(buffer.is_empty());
+
+		// A full buffer without any delimiter is dropped.
+		buffer.extend(&[0x01; 32]);
+		assert_eq!(buffer.take_cobs_frame(), Some(Err(crate::InvalidCobsFrame(15))));
+		assert!(buffer.is_empty());
+	}
+
 	#[test]
 	fn test_stress_test_large_cycles() {
 		const LOOPS:usize = 100_000;
@@ -139,7 +406,7 @@ mod tests {
 		let mut counter:i32 = 0;
 
 		for _ in 0..LOOPS {
-			let data:Vec<i32> = (0..512).map(|x| counter + x as i32).collect();
+			let data:Vec<i32> = (0..512).map(|x| counter + x).collect();
 			buffer.extend(&data);
 			counter += 512;
 
@@ -166,4 +433,47 @@ mod tests {
 		let elapsed:Duration = start.elapsed();
 		println!("Performed {} ops in {:?}", OPERATIONS, elapsed);
 	}
+
+	#[test]
+	fn test_zero_copy_reads() {
+		let mut buffer:CircularBuffer<i32, TEST_CAPACITY> = CircularBuffer::new();
+		buffer.extend(&[0, 1, 2, 3, 4, 5]);
+		buffer.take(4);
+		buffer.extend(&[6, 7, 8, 9]);
+		assert_eq!(buffer.as_slices(), [&[4, 5, 6, 7][..], &[8, 9][..]]);
+		assert_eq!(buffer.peek(3), [&[4, 5, 6][..], &[][..]]);
+		assert_eq!(buffer.peek(5), [&[4, 5, 6, 7][..], &[8][..]]);
+
+		assert_eq!(buffer.consume(3), 3);
+		assert_eq!(buffer.drain().take(2).collect::<Vec<i32>>(), vec![7, 8]);
+		assert!(buffer.is_empty());
+		assert_eq!(buffer.consume(1), 0);
+
+		let mut output:[i32; 4] = [0; 4];
+		buffer.extend(&[1, 2]);
+		assert_eq!(buffer.take_to_buffer(&mut output), 2);
+		assert_eq!(output[..2], [1, 2]);
+	}
+
+	#[test]
+	fn test_io_write() {
+		let mut buffer:CircularBuffer<u8, TEST_CAPACITY> = CircularBuffer::new();
+		assert_eq!(buffer.write(b"hello world").unwrap(), 7);
+		assert!(buffer.write_all(b"!").is_err());
+		assert_eq!(buffer.take_all(), b"hello w");
+	}
+
+	#[test]
+	fn test_collect_and_extend() {
+		let mut buffer:CircularBuffer<i32, TEST_CAPACITY> = (0..4).collect();
+		assert_eq!(buffer.len(), 4);
+		Extend::extend(&mut buffer, 4..10);
+		assert_eq!(buffer.take_all(), (0..7).collect::<Vec<i32>>());
+	}
+
+	#[test]
+	#[cfg(not(any(feature = "statistics", feature = "cursor-timeout")))]
+	fn test_size_without_bookkeeping() {
+		assert!(size_of::<CircularBuffer<u8, 16>>() <= 16 + 6 * size_of::<usize>());
+	}
 }
\ No newline at end of file
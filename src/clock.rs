@@ -0,0 +1,79 @@
+use std::{ sync::{ OnceLock, atomic::{ AtomicU64, Ordering } }, time::{ Duration, Instant } };
+
+
+
+/// A monotonic source of time for time-based features, like cursor timeouts.
+/// The time is the duration since an arbitrary, fixed moment, so a monotonic tick counter can be used on embedded targets, while tests can control time themselves.
+/// Buffers only keep a clock with the 'cursor-timeout' feature, so buffers without it stay small and reads never touch the clock.
+pub trait Clock:Sync {
+
+	/// Return the current time as duration since the moment the clock started.
+	fn now(&self) -> Duration;
+}
+
+
+
+/// The standard clock, based on Instant. Starts at the first time any SystemClock is read.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+impl Clock for SystemClock {
+	fn now(&self) -> Duration {
+		static START_INSTANT:OnceLock<Instant> = OnceLock::new();
+		START_INSTANT.get_or_init(Instant::now).elapsed()
+	}
+}
+
+
+
+/// A clock that only moves when told to, which makes time-based behavior deterministic in tests.
+#[derive(Debug, Default)]
+pub struct ManualClock {
+	nanoseconds:AtomicU64
+}
+impl ManualClock {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new clock, starting at zero.
+	pub const fn new() -> ManualClock {
+		ManualClock {
+			nanoseconds: AtomicU64::new(0)
+		}
+	}
+
+
+
+	/* TIME METHODS */
+
+	/// Move the clock forward by the given duration.
+	pub fn advance(&self, duration:Duration) {
+		self.nanoseconds.fetch_add(duration.as_nanos() as u64, Ordering::AcqRel);
+	}
+
+	/// Set the clock to the given time. Setting a time earlier than the current time breaks monotonicity, so is only useful to reset the clock.
+	pub fn set(&self, time:Duration) {
+		self.nanoseconds.store(time.as_nanos() as u64, Ordering::Release);
+	}
+}
+impl Clock for ManualClock {
+	fn now(&self) -> Duration {
+		Duration::from_nanos(self.nanoseconds.load(Ordering::Acquire))
+	}
+}
+
+
+
+/// A reference to the clock used by a buffer. Clocks are compared by address, so buffers using the same clock compare equal.
+#[derive(Clone, Copy)]
+pub(crate) struct ClockRef(pub(crate) &'static dyn Clock);
+impl ClockRef {
+
+	/// The standard clock.
+	pub(crate) const SYSTEM:ClockRef = ClockRef(&SystemClock);
+}
+impl PartialEq for ClockRef {
+	fn eq(&self, other:&ClockRef) -> bool {
+		std::ptr::addr_eq(self.0, other.0)
+	}
+}
+impl Eq for ClockRef {}
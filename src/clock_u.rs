@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Clock, ManualClock, SystemClock };
+	use std::time::Duration;
+
+
+
+	#[test]
+	fn test_system_clock_is_monotonic() {
+		let first:Duration = SystemClock.now();
+		let second:Duration = SystemClock.now();
+		assert!(second >= first);
+	}
+
+	#[test]
+	fn test_manual_clock() {
+		let clock:ManualClock = ManualClock::new();
+		assert_eq!(clock.now(), Duration::ZERO);
+		clock.advance(Duration::from_millis(5));
+		clock.advance(Duration::from_millis(10));
+		assert_eq!(clock.now(), Duration::from_millis(15));
+		clock.set(Duration::from_secs(1));
+		assert_eq!(clock.now(), Duration::from_secs(1));
+	}
+}
@@ -0,0 +1,50 @@
+/// A complete frame that could not be decoded, like a frame damaged by a dropped byte on a serial link. Holds the amount of dropped encoded bytes.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct InvalidCobsFrame(pub usize);
+
+
+
+/// Encode a frame using Consistent Overhead Byte Stuffing, including the zero delimiter at the end. The encoded frame contains no other zero bytes.
+pub(crate) fn cobs_encode(frame:&[u8]) -> Vec<u8> {
+	let mut output:Vec<u8> = Vec::with_capacity(frame.len() + frame.len() / 254 + 2);
+	let mut code_index:usize = 0;
+	let mut code:u8 = 1;
+	output.push(0);
+	for byte in frame {
+		if *byte != 0 {
+			output.push(*byte);
+			code += 1;
+		}
+
+		// Close the block at each zero byte and at the maximum block size.
+		if *byte == 0 || code == 0xFF {
+			output[code_index] = code;
+			code_index = output.len();
+			output.push(0);
+			code = 1;
+		}
+	}
+	output[code_index] = code;
+	output.push(0);
+	output
+}
+
+/// Decode a frame encoded using Consistent Overhead Byte Stuffing, without the zero delimiter. Returns None if the frame is invalid.
+pub(crate) fn cobs_decode(encoded:&[u8]) -> Option<Vec<u8>> {
+	let mut output:Vec<u8> = Vec::with_capacity(encoded.len());
+	let mut index:usize = 0;
+	while index < encoded.len() {
+		let code:usize = encoded[index] as usize;
+		if code == 0 || index + code > encoded.len() {
+			return None;
+		}
+		output.extend_from_slice(&encoded[index + 1..index + code]);
+		index += code;
+
+		// Each block that is not the maximum size and not the last block was followed by a zero byte.
+		if code != 0xFF && index < encoded.len() {
+			output.push(0);
+		}
+	}
+	Some(output)
+}
@@ -0,0 +1,34 @@
+#[cfg(test)]
+#[cfg(feature = "cobs")]
+mod tests {
+	use crate::cobs::{ cobs_decode, cobs_encode };
+
+
+
+	#[test]
+	fn test_cobs_encode_known_frames() {
+		assert_eq!(cobs_encode(&[]), vec![0x01, 0x00]);
+		assert_eq!(cobs_encode(&[0x00]), vec![0x01, 0x01, 0x00]);
+		assert_eq!(cobs_encode(&[0x11, 0x22, 0x00, 0x33]), vec![0x03, 0x11, 0x22, 0x02, 0x33, 0x00]);
+		assert_eq!(cobs_encode(&[0x11, 0x00, 0x00, 0x00]), vec![0x02, 0x11, 0x01, 0x01, 0x01, 0x00]);
+	}
+
+	#[test]
+	fn test_cobs_round_trip_long_frames() {
+		for length in [253, 254, 255, 600] {
+			let frame:Vec<u8> = (0..length).map(|index| (index % 7) as u8 + 1).collect();
+			let encoded:Vec<u8> = cobs_encode(&frame);
+			assert_eq!(encoded.iter().position(|byte| *byte == 0), Some(encoded.len() - 1));
+			assert_eq!(cobs_decode(&encoded[..encoded.len() - 1]), Some(frame));
+		}
+		let frame:Vec<u8> = (0..1000).map(|index| (index % 256) as u8).collect();
+		let encoded:Vec<u8> = cobs_encode(&frame);
+		assert_eq!(cobs_decode(&encoded[..encoded.len() - 1]), Some(frame));
+	}
+
+	#[test]
+	fn test_cobs_decode_rejects_truncated_frames() {
+		assert_eq!(cobs_decode(&[0x05, 0x11, 0x22]), None);
+		assert_eq!(cobs_decode(&[0x03, 0x11, 0x22, 0x02, 0x33]), Some(vec![0x11, 0x22, 0x00, 0x33]));
+	}
+}
@@ -0,0 +1,71 @@
+use crate::{ BufferPeek, BufferRead, CircularBufferCore, CursorBufferRead, CursorPolicy, MultiCursorPolicy, ReadCursor };
+use std::io::{ BufRead, Read, Result };
+
+
+
+/// Reads from a buffer with multiple read cursors using a single cursor, so a subscriber can be handed a standard reader.
+/// For byte buffers, implements io::Read and io::BufRead. An empty buffer reads as the end of the stream, so read again after more data has been written.
+pub struct CursorReader<'a, B> {
+	buffer:&'a mut B,
+	cursor:ReadCursor
+}
+impl<'a, B> CursorReader<'a, B> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new reader, reading from the buffer using the given cursor.
+	pub fn new(buffer:&'a mut B, cursor:ReadCursor) -> CursorReader<'a, B> {
+		CursorReader { buffer, cursor }
+	}
+
+	/// Get back the cursor, ending the borrow of the buffer.
+	pub fn into_cursor(self) -> ReadCursor {
+		self.cursor
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get a reference to the buffer.
+	pub fn buffer(&self) -> &B {
+		self.buffer
+	}
+
+	/// Get a reference to the cursor used for reading.
+	pub fn cursor(&self) -> &ReadCursor {
+		&self.cursor
+	}
+}
+impl<T, B:CursorBufferRead<T>> BufferRead<T> for CursorReader<'_, B> {
+	fn len(&self) -> usize {
+		self.buffer.len(&self.cursor)
+	}
+	fn take_to_buffer(&mut self, output:&mut [T]) -> usize {
+		self.buffer.take_to_buffer(output, &self.cursor)
+	}
+}
+impl<B:CursorBufferRead<u8>> Read for CursorReader<'_, B> {
+	fn read(&mut self, output:&mut [u8]) -> Result<usize> {
+		Ok(self.buffer.take_to_buffer(output, &self.cursor))
+	}
+}
+impl<B:CursorBufferRead<u8>> BufRead for CursorReader<'_, B> {
+	fn fill_buf(&mut self) -> Result<&[u8]> {
+		Ok(self.buffer.peek_contiguous(&self.cursor))
+	}
+	fn consume(&mut self, amount:usize) {
+		self.buffer.consume(amount, &self.cursor);
+	}
+}
+impl<T:Copy, S:AsRef<[T]> + AsMut<[T]>, C:CursorPolicy<T> + MultiCursorPolicy> BufferPeek<T> for CursorReader<'_, CircularBufferCore<T, S, C>> {
+	fn as_slices(&self) -> [&[T]; 2] {
+		self.buffer.cursor_unread_slices(self.cursor.0)
+	}
+	fn consume(&mut self, amount:usize) -> usize {
+		CursorBufferRead::consume(self.buffer, amount, &self.cursor)
+	}
+	fn is_full(&self) -> bool {
+		self.buffer.is_full(&self.cursor)
+	}
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ BufferRead, CircularBufferMultiRead, CircularBufferMultiReadDyn, CursorReader, ReadCursor };
+	use std::io::{ BufRead, Read };
+
+
+
+	#[test]
+	fn test_cursor_reader_read() {
+		let mut buffer:CircularBufferMultiRead<u8, 16, 2> = CircularBufferMultiRead::new();
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		buffer.extend(b"hello world");
+
+		let mut reader:CursorReader<CircularBufferMultiRead<u8, 16, 2>> = CursorReader::new(&mut buffer, cursor_a);
+		let mut output:String = String::new();
+		reader.read_to_string(&mut output).unwrap();
+		assert_eq!(output, "hello world");
+		let cursor_a:ReadCursor = reader.into_cursor();
+
+		assert!(buffer.is_empty(&cursor_a));
+		assert_eq!(buffer.take_all(&cursor_b), b"hello world".to_vec());
+	}
+
+	#[test]
+	fn test_cursor_reader_buf_read_across_wrap() {
+		let mut buffer:CircularBufferMultiReadDyn<u8> = CircularBufferMultiReadDyn::new(8);
+		let cursor:ReadCursor = buffer.create_read_cursor();
+		buffer.extend(b"junk");
+		buffer.take(4, &cursor);
+		buffer.extend(b"ab\ncd\n");
+
+		let mut reader:CursorReader<CircularBufferMultiReadDyn<u8>> = CursorReader::new(&mut buffer, cursor);
+		assert_eq!(reader.fill_buf().unwrap(), b"ab\nc");
+		let lines:Vec<String> = (&mut reader).lines().map(|line| line.unwrap()).collect();
+		assert_eq!(lines, vec!["ab", "cd"]);
+		assert!(reader.fill_buf().unwrap().is_empty());
+	}
+
+	#[test]
+	fn test_cursor_reader_buffer_read() {
+		let mut buffer:CircularBufferMultiRead<i32, 8, 1> = CircularBufferMultiRead::new();
+		let cursor:ReadCursor = buffer.create_read_cursor();
+		buffer.extend(&[1, 2, 3]);
+
+		let mut reader:CursorReader<CircularBufferMultiRead<i32, 8, 1>> = CursorReader::new(&mut buffer, cursor);
+		assert_eq!(BufferRead::len(&reader), 3);
+		assert_eq!(reader.take(2), vec![1, 2]);
+		assert_eq!(reader.take_all(), vec![3]);
+	}
+}
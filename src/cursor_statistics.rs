@@ -0,0 +1,52 @@
+/// Keeps track of how many samples a single read cursor took and skipped, which allows telling an idle reader from a slow one.
+/// An idle reader has not read in a long time, as shown by the last read time of the cursor, while a slow reader reads regularly but keeps many unread samples.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct CursorStatistics {
+	taken_count:usize,
+	skipped_count:usize
+}
+impl CursorStatistics {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create new empty statistics.
+	pub const fn new() -> CursorStatistics {
+		CursorStatistics {
+			taken_count: 0,
+			skipped_count: 0
+		}
+	}
+
+
+
+	/* RECORDING METHODS */
+
+	/// Record samples taken using the cursor.
+	pub(crate) fn record_taken(&mut self, amount:usize) {
+		self.taken_count += amount;
+	}
+
+	/// Record samples the cursor skipped without reading them.
+	pub(crate) fn record_skipped(&mut self, amount:usize) {
+		self.skipped_count += amount;
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Return the total amount of samples taken using the cursor.
+	pub fn taken_count(&self) -> usize {
+		self.taken_count
+	}
+
+	/// Return the total amount of samples the cursor skipped without reading them, by skipping to the end of data or by having them overwritten.
+	pub fn skipped_count(&self) -> usize {
+		self.skipped_count
+	}
+}
+impl Default for CursorStatistics {
+	fn default() -> CursorStatistics {
+		CursorStatistics::new()
+	}
+}
@@ -0,0 +1,18 @@
+#[cfg(test)]
+#[cfg(feature = "statistics")]
+mod tests {
+	use crate::CursorStatistics;
+
+
+
+	#[test]
+	fn test_cursor_statistics_counts() {
+		let mut statistics:CursorStatistics = CursorStatistics::new();
+		statistics.record_taken(5);
+		statistics.record_taken(2);
+		statistics.record_skipped(3);
+
+		assert_eq!(statistics.taken_count(), 7);
+		assert_eq!(statistics.skipped_count(), 3);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+/// Get the minimum and maximum of the samples in each bucket, for samples split into two parts. The samples are divided as evenly as possible over the buckets.
+/// If fewer samples than buckets are given, each bucket holds a single sample.
+pub(crate) fn downsample_min_max<T:PartialOrd + Clone>(parts:[&[T]; 2], buckets:usize) -> Vec<(T, T)> {
+	let sample_count:usize = parts[0].len() + parts[1].len();
+	let bucket_count:usize = buckets.min(sample_count);
+	let mut output:Vec<(T, T)> = Vec::with_capacity(bucket_count);
+	if bucket_count == 0 {
+		return output;
+	}
+
+	// Walk over all samples once, starting a new bucket at each bucket boundary.
+	let mut bucket_end:usize = 0;
+	for (sample_index, sample) in parts[0].iter().chain(parts[1]).enumerate() {
+		if sample_index == bucket_end {
+			bucket_end = (output.len() + 1) * sample_count / bucket_count;
+			output.push((sample.clone(), sample.clone()));
+			continue;
+		}
+		let (minimum, maximum) = output.last_mut().unwrap();
+		if sample < minimum {
+			*minimum = sample.clone();
+		}
+		if sample > maximum {
+			*maximum = sample.clone();
+		}
+	}
+	output
+}
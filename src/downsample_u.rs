@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod tests {
+	use crate::downsample::downsample_min_max;
+
+
+
+	#[test]
+	fn test_downsample_even_buckets() {
+		let samples:Vec<i32> = vec![1, 5, -2, 3, 0, 0, 9, 8];
+		assert_eq!(downsample_min_max([&samples, &[]], 4), vec![(1, 5), (-2, 3), (0, 0), (8, 9)]);
+		assert_eq!(downsample_min_max([&samples[..3], &samples[3..]], 2), vec![(-2, 5), (0, 9)]);
+	}
+
+	#[test]
+	fn test_downsample_uneven_buckets() {
+		let samples:Vec<i32> = (0..10).collect();
+		assert_eq!(downsample_min_max([&samples[..7], &samples[7..]], 3), vec![(0, 2), (3, 5), (6, 9)]);
+	}
+
+	#[test]
+	fn test_downsample_fewer_samples_than_buckets() {
+		let samples:Vec<i32> = vec![4, 2];
+		assert_eq!(downsample_min_max([&samples, &[]], 10), vec![(4, 4), (2, 2)]);
+		assert_eq!(downsample_min_max::<i32>([&[], &[]], 10), vec![]);
+		assert_eq!(downsample_min_max([&samples, &[]], 0), vec![]);
+	}
+}
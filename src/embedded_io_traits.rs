@@ -0,0 +1,157 @@
+use crate::{ BufferRead, CircularBufferCore, Consumer, CursorBufferRead, CursorPolicy, CursorReader, Producer, SampleCopy, SingleReader };
+use embedded_io::{ ErrorKind, ErrorType, ReadReady, WriteReady };
+
+
+
+// The single-threaded buffers can not block, as nothing can write to them while a read waits. embedded-io defines Ok(0) as the end of the stream, so reading from an empty buffer and writing to a full buffer return an error instead.
+// Callers should check ReadReady and WriteReady before reading or writing. The Consumer and Producer of the concurrent buffer do block, using their wait strategy, and also implement the embedded-io-async traits.
+
+
+
+/// Convert the amount of samples read from a single-threaded buffer to an embedded-io read result.
+/// An empty buffer is not the end of the stream, as more data may be written later, which embedded-io requires to be reported as an error instead of reading nothing.
+fn read_result(output_size:usize, taken:usize) -> Result<usize, ErrorKind> {
+	if taken == 0 && output_size != 0 {
+		Err(ErrorKind::Other)
+	} else {
+		Ok(taken)
+	}
+}
+
+/// Convert the amount of samples written by the buffer to an embedded-io write result.
+/// A full buffer can not accept any data, which embedded-io requires to be reported as an error instead of writing nothing.
+fn write_result(input_size:usize, written:usize) -> Result<usize, ErrorKind> {
+	if written == 0 && input_size != 0 {
+		Err(ErrorKind::OutOfMemory)
+	} else {
+		Ok(written)
+	}
+}
+
+
+
+/* CIRCULAR BUFFER CORE */
+
+impl<S:AsRef<[u8]> + AsMut<[u8]>, C:CursorPolicy<u8>> ErrorType for CircularBufferCore<u8, S, C> {
+	type Error = ErrorKind;
+}
+impl<S:AsRef<[u8]> + AsMut<[u8]>, M:SampleCopy<u8>> embedded_io::Read for CircularBufferCore<u8, S, SingleReader<M>> {
+	fn read(&mut self, output:&mut [u8]) -> Result<usize, ErrorKind> {
+		read_result(output.len(), self.take_to_buffer(output))
+	}
+}
+impl<S:AsRef<[u8]> + AsMut<[u8]>, M:SampleCopy<u8>> ReadReady for CircularBufferCore<u8, S, SingleReader<M>> {
+	fn read_ready(&mut self) -> Result<bool, ErrorKind> {
+		Ok(!BufferRead::is_empty(self))
+	}
+}
+impl<S:AsRef<[u8]> + AsMut<[u8]>, C:CursorPolicy<u8>> embedded_io::Write for CircularBufferCore<u8, S, C> {
+	fn write(&mut self, input:&[u8]) -> Result<usize, ErrorKind> {
+		write_result(input.len(), self.extend(input))
+	}
+	fn flush(&mut self) -> Result<(), ErrorKind> {
+		Ok(())
+	}
+}
+impl<S:AsRef<[u8]> + AsMut<[u8]>, C:CursorPolicy<u8>> WriteReady for CircularBufferCore<u8, S, C> {
+	fn write_ready(&mut self) -> Result<bool, ErrorKind> {
+		Ok(!self.frozen && self.capacity.saturating_sub(self.largest_len() + 1) != 0)
+	}
+}
+
+
+
+/* CURSOR READER */
+
+impl<B:CursorBufferRead<u8>> ErrorType for CursorReader<'_, B> {
+	type Error = ErrorKind;
+}
+impl<B:CursorBufferRead<u8>> embedded_io::Read for CursorReader<'_, B> {
+	fn read(&mut self, output:&mut [u8]) -> Result<usize, ErrorKind> {
+		read_result(output.len(), BufferRead::take_to_buffer(self, output))
+	}
+}
+impl<B:CursorBufferRead<u8>> ReadReady for CursorReader<'_, B> {
+	fn read_ready(&mut self) -> Result<bool, ErrorKind> {
+		Ok(!BufferRead::is_empty(self))
+	}
+}
+
+
+
+/* CONCURRENT CONSUMER */
+
+impl<const CAPACITY:usize> ErrorType for Consumer<u8, CAPACITY> {
+	type Error = ErrorKind;
+}
+impl<const CAPACITY:usize> embedded_io::Read for Consumer<u8, CAPACITY> {
+
+	// Reads wait until at least one byte is available, so Ok(0) is only returned at the end of the stream.
+	fn read(&mut self, output:&mut [u8]) -> Result<usize, ErrorKind> {
+		if !output.is_empty() {
+			self.wait_for_data(self.wait_strategy());
+		}
+		Ok(self.take_to_buffer(output))
+	}
+}
+impl<const CAPACITY:usize> ReadReady for Consumer<u8, CAPACITY> {
+	fn read_ready(&mut self) -> Result<bool, ErrorKind> {
+		Ok(!self.is_empty() || !self.is_peer_alive())
+	}
+}
+impl<const CAPACITY:usize> embedded_io_async::Read for Consumer<u8, CAPACITY> {
+	async fn read(&mut self, output:&mut [u8]) -> Result<usize, ErrorKind> {
+		if !output.is_empty() {
+			self.wait_for_data_async().await;
+		}
+		Ok(self.take_to_buffer(output))
+	}
+}
+
+
+
+/* CONCURRENT PRODUCER */
+
+impl<const CAPACITY:usize> ErrorType for Producer<u8, CAPACITY> {
+	type Error = ErrorKind;
+}
+impl<const CAPACITY:usize> embedded_io::Write for Producer<u8, CAPACITY> {
+
+	// Writes wait until at least one byte can be written. Once all consumers are dropped or the producer is closed, nothing will ever be read, so writes fail.
+	fn write(&mut self, input:&[u8]) -> Result<usize, ErrorKind> {
+		if input.is_empty() {
+			return Ok(0);
+		}
+		if !self.wait_for_space(self.wait_strategy()) {
+			return Err(ErrorKind::BrokenPipe);
+		}
+		Ok(self.extend(input))
+	}
+
+	// Flushing waits until all consumers have read everything written.
+	fn flush(&mut self) -> Result<(), ErrorKind> {
+		self.wait_until_read(self.wait_strategy());
+		Ok(())
+	}
+}
+impl<const CAPACITY:usize> WriteReady for Producer<u8, CAPACITY> {
+	fn write_ready(&mut self) -> Result<bool, ErrorKind> {
+		Ok(self.free_space() != 0 || self.is_closed() || !self.is_peer_alive())
+	}
+}
+impl<const CAPACITY:usize> embedded_io_async::Write for Producer<u8, CAPACITY> {
+	async fn write(&mut self, input:&[u8]) -> Result<usize, ErrorKind> {
+		if input.is_empty() {
+			return Ok(0);
+		}
+		if !self.wait_for_space_async().await {
+			return Err(ErrorKind::BrokenPipe);
+		}
+		Ok(self.extend(input))
+	}
+
+	async fn flush(&mut self) -> Result<(), ErrorKind> {
+		self.wait_until_read_async().await;
+		Ok(())
+	}
+}
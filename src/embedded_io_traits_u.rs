@@ -0,0 +1,141 @@
+#[cfg(test)]
+#[cfg(feature = "embedded-io")]
+mod tests {
+	use crate::{ CircularBuffer, CircularBufferConcurrent, CircularBufferDyn, CircularBufferMultiRead, CursorReader, ReadCursor, WaitStrategy };
+	use embedded_io::{ ErrorKind, Read, ReadReady, Write, WriteReady };
+	use std::{ future::Future, pin::pin, task::{ Context, Poll, Waker }, thread, time::Duration };
+
+
+
+	#[test]
+	fn test_read_and_write() {
+		let mut buffer:CircularBuffer<u8, 8> = CircularBuffer::new();
+		assert_eq!(buffer.write(b"hello world"), Ok(7));
+		assert_eq!(buffer.write(b"!"), Err(ErrorKind::OutOfMemory));
+		assert_eq!(buffer.write(b""), Ok(0));
+		assert_eq!(buffer.flush(), Ok(()));
+
+		let mut output:[u8; 5] = [0; 5];
+		assert_eq!(buffer.read(&mut output), Ok(5));
+		assert_eq!(&output, b"hello");
+		assert_eq!(buffer.read(&mut output), Ok(2));
+		assert_eq!(buffer.read(&mut output), Err(ErrorKind::Other));
+		assert_eq!(buffer.read(&mut []), Ok(0));
+	}
+
+	#[test]
+	fn test_write_all_and_read_exact_dyn() {
+		let mut buffer:CircularBufferDyn<u8> = CircularBufferDyn::new(16);
+		buffer.write_all(b"0123456789").unwrap();
+		let mut output:[u8; 10] = [0; 10];
+		buffer.read_exact(&mut output).unwrap();
+		assert_eq!(&output, b"0123456789");
+	}
+
+	#[test]
+	fn test_cursor_reader() {
+		let mut buffer:CircularBufferMultiRead<u8, 16, 2> = CircularBufferMultiRead::new();
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		buffer.write_all(b"shared").unwrap();
+
+		let mut output:[u8; 6] = [0; 6];
+		assert_eq!(CursorReader::new(&mut buffer, cursor_a).read(&mut output), Ok(6));
+		assert_eq!(&output, b"shared");
+		assert_eq!(CursorReader::new(&mut buffer, cursor_b).read(&mut output[..3]), Ok(3));
+		assert_eq!(&output[..3], b"sha");
+	}
+
+	#[test]
+	fn test_read_and_write_ready() {
+		let mut buffer:CircularBuffer<u8, 4> = CircularBuffer::new();
+		assert_eq!(buffer.read_ready(), Ok(false));
+		assert_eq!(buffer.write_ready(), Ok(true));
+		assert_eq!(buffer.write(b"full"), Ok(3));
+		assert_eq!(buffer.read_ready(), Ok(true));
+		assert_eq!(buffer.write_ready(), Ok(false));
+
+		buffer.freeze();
+		let mut output:[u8; 4] = [0; 4];
+		assert_eq!(buffer.read(&mut output), Ok(3));
+		assert_eq!(buffer.write_ready(), Ok(false));
+	}
+
+	#[test]
+	fn test_cursor_reader_ready() {
+		let mut buffer:CircularBufferMultiRead<u8, 16, 2> = CircularBufferMultiRead::new();
+		let cursor:ReadCursor = buffer.create_read_cursor();
+		buffer.write_all(b"data").unwrap();
+		let mut reader:CursorReader<CircularBufferMultiRead<u8, 16, 2>> = CursorReader::new(&mut buffer, cursor);
+		assert_eq!(reader.read_ready(), Ok(true));
+		let mut output:[u8; 4] = [0; 4];
+		assert_eq!(reader.read(&mut output), Ok(4));
+		assert_eq!(reader.read_ready(), Ok(false));
+	}
+
+	#[test]
+	fn test_concurrent_blocking_read_and_write() {
+		let (mut producer, mut consumers) = CircularBufferConcurrent::<u8, 8>::new().split(1);
+		producer.set_wait_strategy(WaitStrategy::SpinThenPark(0, Duration::from_secs(60)));
+		consumers[0].set_wait_strategy(WaitStrategy::SpinThenPark(0, Duration::from_secs(60)));
+		let producer_thread:thread::JoinHandle<()> = thread::spawn(move || {
+			producer.write_all(b"a longer message than fits").unwrap();
+			producer.flush().unwrap();
+			assert_eq!(producer.write_ready(), Ok(true));
+		});
+
+		let mut output:Vec<u8> = Vec::new();
+		let mut chunk:[u8; 5] = [0; 5];
+		loop {
+			let read:usize = consumers[0].read(&mut chunk).unwrap();
+			if read == 0 {
+				break;
+			}
+			output.extend_from_slice(&chunk[..read]);
+		}
+		producer_thread.join().unwrap();
+		assert_eq!(output, b"a longer message than fits");
+		assert_eq!(consumers[0].read_ready(), Ok(true));
+	}
+
+	#[test]
+	fn test_concurrent_write_without_consumers() {
+		let (mut producer, consumers) = CircularBufferConcurrent::<u8, 8>::new().split(1);
+		drop(consumers);
+		assert_eq!(producer.write_ready(), Ok(true));
+		assert_eq!(producer.write(b"lost"), Err(ErrorKind::BrokenPipe));
+		assert_eq!(producer.write(b""), Ok(0));
+	}
+
+	#[test]
+	fn test_concurrent_async_read_and_write() {
+		let (mut producer, mut consumers) = CircularBufferConcurrent::<u8, 4>::new().split(1);
+		let mut context:Context = Context::from_waker(Waker::noop());
+		let mut output:[u8; 4] = [0; 4];
+		{
+			let mut read = pin!(embedded_io_async::Read::read(&mut consumers[0], &mut output));
+			assert_eq!(read.as_mut().poll(&mut context), Poll::Pending);
+			assert_eq!(producer.write(b"ab"), Ok(2));
+			assert_eq!(read.as_mut().poll(&mut context), Poll::Ready(Ok(2)));
+		}
+		assert_eq!(&output[..2], b"ab");
+
+		assert_eq!(producer.write(b"cdef"), Ok(3));
+		{
+			let mut write = pin!(embedded_io_async::Write::write(&mut producer, b"g"));
+			assert_eq!(write.as_mut().poll(&mut context), Poll::Pending);
+			assert_eq!(consumers[0].read(&mut output[..1]), Ok(1));
+			assert_eq!(write.as_mut().poll(&mut context), Poll::Ready(Ok(1)));
+		}
+		{
+			let mut flush = pin!(embedded_io_async::Write::flush(&mut producer));
+			assert_eq!(flush.as_mut().poll(&mut context), Poll::Pending);
+			assert_eq!(consumers[0].read(&mut output), Ok(3));
+			assert_eq!(flush.as_mut().poll(&mut context), Poll::Ready(Ok(())));
+		}
+
+		drop(producer);
+		let mut read = pin!(embedded_io_async::Read::read(&mut consumers[0], &mut output));
+		assert_eq!(read.as_mut().poll(&mut context), Poll::Ready(Ok(0)));
+	}
+}
@@ -0,0 +1,138 @@
+use crate::{ BufferRead, BufferWrite, CursorBufferRead, ReadCursor };
+
+
+
+/// Wraps a buffer and deterministically injects faults according to a seed, to test how downstream code handles short reads and writes.
+/// Injects partial writes, forced overflows that store nothing, and spurious reads that take nothing. All fault rates start at 0.
+pub struct FaultInjector<B> {
+	buffer:B,
+	random_state:u64,
+	partial_write_rate:f64,
+	overflow_rate:f64,
+	empty_read_rate:f64,
+	injected_count:usize
+}
+impl<B> FaultInjector<B> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new fault injector around the given buffer. The same seed always injects the same faults for the same operations.
+	pub fn new(buffer:B, seed:u64) -> FaultInjector<B> {
+		FaultInjector {
+			buffer,
+			random_state: seed.max(1),
+			partial_write_rate: 0.0,
+			overflow_rate: 0.0,
+			empty_read_rate: 0.0,
+			injected_count: 0
+		}
+	}
+
+	/// Get back the buffer.
+	pub fn into_inner(self) -> B {
+		self.buffer
+	}
+
+
+
+	/* FAULT METHODS */
+
+	/// Set the chance from 0.0 to 1.0 that a write only stores a random part of the samples that would fit.
+	pub fn set_partial_write_rate(&mut self, rate:f64) {
+		self.partial_write_rate = rate;
+	}
+
+	/// Set the chance from 0.0 to 1.0 that a write stores nothing, as if the buffer was full.
+	pub fn set_overflow_rate(&mut self, rate:f64) {
+		self.overflow_rate = rate;
+	}
+
+	/// Set the chance from 0.0 to 1.0 that a read takes nothing, even though samples are available.
+	pub fn set_empty_read_rate(&mut self, rate:f64) {
+		self.empty_read_rate = rate;
+	}
+
+	/// Get the next pseudo-random number using xorshift.
+	fn next_random(&mut self) -> u64 {
+		self.random_state ^= self.random_state << 13;
+		self.random_state ^= self.random_state >> 7;
+		self.random_state ^= self.random_state << 17;
+		self.random_state
+	}
+
+	/// Randomly decide wether or not to inject a fault with the given chance.
+	fn inject(&mut self, rate:f64) -> bool {
+		let injected:bool = ((self.next_random() >> 11) as f64 / (1u64 << 53) as f64) < rate;
+		if injected {
+			self.injected_count += 1;
+		}
+		injected
+	}
+
+	/// Get the amount of samples a write of the given size may store.
+	fn write_size(&mut self, size:usize) -> usize {
+		if size == 0 {
+			return 0;
+		}
+		if self.inject(self.overflow_rate) {
+			return 0;
+		}
+		if self.inject(self.partial_write_rate) {
+			return (self.next_random() % size as u64) as usize;
+		}
+		size
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get a reference to the buffer.
+	pub fn buffer(&self) -> &B {
+		&self.buffer
+	}
+
+	/// Get a mutable reference to the buffer. Operations done using this reference are never faulted.
+	pub fn buffer_mut(&mut self) -> &mut B {
+		&mut self.buffer
+	}
+
+	/// Get the total amount of injected faults.
+	pub fn injected_count(&self) -> usize {
+		self.injected_count
+	}
+}
+impl<T, B:BufferWrite<T>> BufferWrite<T> for FaultInjector<B> {
+	fn extend(&mut self, input:&[T]) -> usize {
+		let write_size:usize = self.write_size(input.len());
+		self.buffer.extend(&input[..write_size])
+	}
+}
+impl<T, B:BufferRead<T>> BufferRead<T> for FaultInjector<B> {
+	fn len(&self) -> usize {
+		self.buffer.len()
+	}
+	fn take_to_buffer(&mut self, output:&mut [T]) -> usize {
+		if !output.is_empty() && self.inject(self.empty_read_rate) {
+			return 0;
+		}
+		self.buffer.take_to_buffer(output)
+	}
+}
+impl<T, B:CursorBufferRead<T>> CursorBufferRead<T> for FaultInjector<B> {
+	fn len(&self, cursor:&ReadCursor) -> usize {
+		self.buffer.len(cursor)
+	}
+	fn take_to_buffer(&mut self, output:&mut [T], cursor:&ReadCursor) -> usize {
+		if !output.is_empty() && self.inject(self.empty_read_rate) {
+			return 0;
+		}
+		self.buffer.take_to_buffer(output, cursor)
+	}
+	fn peek_contiguous(&self, cursor:&ReadCursor) -> &[T] {
+		self.buffer.peek_contiguous(cursor)
+	}
+	fn consume(&mut self, amount:usize, cursor:&ReadCursor) -> usize {
+		self.buffer.consume(amount, cursor)
+	}
+}
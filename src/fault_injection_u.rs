@@ -0,0 +1,51 @@
+#[cfg(test)]
+#[cfg(feature = "test-util")]
+mod tests {
+	use crate::{ BufferRead, BufferWrite, CircularBuffer, CircularBufferMultiRead, CursorBufferRead, FaultInjector, ReadCursor };
+
+
+
+	#[test]
+	fn test_no_faults_by_default() {
+		let mut injector:FaultInjector<CircularBuffer<i32, 8>> = FaultInjector::new(CircularBuffer::new(), 5);
+		assert_eq!(injector.extend(&[1, 2, 3]), 3);
+		assert_eq!(injector.take_all(), vec![1, 2, 3]);
+		assert_eq!(injector.injected_count(), 0);
+	}
+
+	#[test]
+	fn test_faults_are_deterministic() {
+		let run = |seed:u64| {
+			let mut injector:FaultInjector<CircularBuffer<i32, 64>> = FaultInjector::new(CircularBuffer::new(), seed);
+			injector.set_partial_write_rate(0.3);
+			injector.set_overflow_rate(0.1);
+			injector.set_empty_read_rate(0.3);
+			let results:Vec<(usize, usize)> = (0..100).map(|_| (injector.extend(&[1, 2, 3, 4]), injector.take_to_buffer(&mut [0; 4]))).collect();
+			(results, injector.injected_count())
+		};
+		assert_eq!(run(42), run(42));
+		assert_ne!(run(42), run(43));
+
+		let (results, injected_count) = run(42);
+		assert!(injected_count > 0);
+		assert!(results.iter().any(|(written, _)| *written == 0));
+		assert!(results.iter().any(|(written, _)| *written > 0 && *written < 4));
+		assert!(results.iter().all(|(written, _)| *written <= 4));
+	}
+
+	#[test]
+	fn test_empty_reads_with_cursor() {
+		let mut buffer:CircularBufferMultiRead<i32, 8, 1> = CircularBufferMultiRead::new();
+		let cursor:ReadCursor = buffer.create_read_cursor();
+		buffer.extend(&[1, 2, 3]);
+		let mut injector:FaultInjector<CircularBufferMultiRead<i32, 8, 1>> = FaultInjector::new(buffer, 7);
+		injector.set_empty_read_rate(1.0);
+		assert_eq!(CursorBufferRead::take_to_buffer(&mut injector, &mut [0; 3], &cursor), 0);
+		assert_eq!(CursorBufferRead::len(&injector, &cursor), 3);
+
+		injector.set_empty_read_rate(0.0);
+		let mut output:[i32; 3] = [0; 3];
+		assert_eq!(CursorBufferRead::take_to_buffer(&mut injector, &mut output, &cursor), 3);
+		assert_eq!(output, [1, 2, 3]);
+	}
+}
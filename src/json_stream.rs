@@ -0,0 +1,142 @@
+use crate::BufferPeek;
+use serde::de::DeserializeOwned;
+use serde_json::{ Deserializer, Error };
+use std::{ io::Read, marker::PhantomData };
+
+
+
+/// Reads a stream of JSON documents, like NDJSON, from a byte buffer.
+/// Complete documents are parsed directly from the buffer as they arrive, while an incomplete trailing document is left in the buffer until the rest of it has been written.
+/// An unterminated number at the end of the data is kept as well, as it might still be continued. After an invalid document, the stream continues at the next line.
+/// An invalid document is only reported once its line ends, or once the buffer is full, so the rest of its line is not reported as another invalid document.
+/// A document crossing the wrap of the buffer is parsed from both slices in place, reading only up to its end.
+pub struct JsonStream<T> {
+	pending_len:usize,
+	document_type:PhantomData<T>
+}
+impl<T:DeserializeOwned> JsonStream<T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new stream without any pending data.
+	pub fn new() -> JsonStream<T> {
+		JsonStream {
+			pending_len: 0,
+			document_type: PhantomData
+		}
+	}
+
+
+
+	/* READING METHODS */
+
+	/// Parse all complete documents in the buffer and consume them. Returns a result for each document, in order.
+	pub fn read_from<B:BufferPeek<u8>>(&mut self, buffer:&mut B) -> Vec<Result<T, Error>> {
+		let mut documents:Vec<Result<T, Error>> = Vec::new();
+		let buffer_full:bool = buffer.is_full();
+		loop {
+			let [first, second] = buffer.as_slices();
+			if second.is_empty() {
+				let consumed:usize = JsonStream::parse_documents(first, true, buffer_full, &mut documents);
+				BufferPeek::consume(buffer, consumed);
+				break;
+			}
+
+			// Parse the samples up to the wrap. Once everything up to the wrap is consumed, the samples after the wrap are parsed in the next iteration.
+			let mut consumed:usize = JsonStream::parse_documents(first, false, buffer_full, &mut documents);
+			if consumed < first.len() {
+				match JsonStream::parse_crossing_document(&first[consumed..], second, buffer_full, &mut documents) {
+					Some(document_len) => consumed += document_len,
+					None => {
+						BufferPeek::consume(buffer, consumed);
+						break;
+					}
+				}
+			}
+			BufferPeek::consume(buffer, consumed);
+		}
+		self.pending_len = buffer.len();
+		documents
+	}
+
+	/// Parse the document that starts before the wrap of the buffer and ends after it. Both parts are read in place, only up to the end of the document.
+	/// Returns the amount of bytes parsed, including the line of an invalid document, or None if the document is incomplete.
+	fn parse_crossing_document(start:&[u8], rest:&[u8], buffer_full:bool, documents:&mut Vec<Result<T, Error>>) -> Option<usize> {
+		let data_len:usize = start.len() + rest.len();
+		let mut parser = Deserializer::from_reader(start.chain(rest)).into_iter::<T>();
+		match parser.next()? {
+			Ok(document) => {
+
+				// A number at the very end of the data might still be continued by the next write.
+				let document_end:usize = parser.byte_offset();
+				if document_end == data_len && rest.last().is_some_and(u8::is_ascii_digit) {
+					return None;
+				}
+				documents.push(Ok(document));
+				Some(document_end)
+			},
+			Err(error) if error.is_eof() => None,
+			Err(error) => {
+				let line_end:Option<usize> = start.iter().chain(rest).position(|byte| *byte == b'\n');
+				let skipped_len:usize = match line_end {
+					Some(line_end) => line_end + 1,
+					None if buffer_full => data_len,
+					None => return None
+				};
+				documents.push(Err(error));
+				Some(skipped_len)
+			}
+		}
+	}
+
+	/// Parse all complete documents in the data, stopping at an incomplete trailing document. Returns the amount of bytes parsed.
+	/// An invalid document without a next line is left unparsed as well, as its line may still continue, unless the data is the end of a full buffer.
+	fn parse_documents(data:&[u8], is_end_of_data:bool, buffer_full:bool, documents:&mut Vec<Result<T, Error>>) -> usize {
+		let mut consumed:usize = 0;
+		loop {
+			consumed += data[consumed..].iter().take_while(|byte| byte.is_ascii_whitespace()).count();
+			let mut parser = Deserializer::from_slice(&data[consumed..]).into_iter::<T>();
+			match parser.next() {
+				None => return data.len(),
+				Some(Ok(document)) => {
+
+					// A number at the very end of the data might still be continued by the next write.
+					let document_end:usize = consumed + parser.byte_offset();
+					if document_end == data.len() && data[document_end - 1].is_ascii_digit() {
+						return consumed;
+					}
+					documents.push(Ok(document));
+					consumed = document_end;
+				},
+				Some(Err(error)) if error.is_eof() => return consumed,
+				Some(Err(error)) => {
+
+					// Skip the invalid document up to the next line. A full buffer can not receive the rest of the line, so its data is dropped.
+					match data[consumed..].iter().position(|byte| *byte == b'\n') {
+						Some(line_end) => consumed += line_end + 1,
+						None if is_end_of_data && buffer_full => {
+							documents.push(Err(error));
+							return data.len();
+						},
+						None => return consumed
+					}
+					documents.push(Err(error));
+				}
+			}
+		}
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the amount of bytes of incomplete documents left in the buffer by the last read, waiting for more data.
+	pub fn pending_len(&self) -> usize {
+		self.pending_len
+	}
+}
+impl<T:DeserializeOwned> Default for JsonStream<T> {
+	fn default() -> JsonStream<T> {
+		JsonStream::new()
+	}
+}
@@ -0,0 +1,121 @@
+#[cfg(test)]
+#[cfg(feature = "json")]
+mod tests {
+	use crate::{ CircularBuffer, CircularBufferMultiRead, CursorReader, JsonStream, ReadCursor };
+	use serde_json::Value;
+
+
+
+	#[test]
+	fn test_json_stream_keeps_incomplete_documents() {
+		let mut buffer:CircularBuffer<u8, 32> = CircularBuffer::new();
+		let mut stream:JsonStream<Value> = JsonStream::new();
+		buffer.extend(b"{\"a\":1}\n{\"b\":");
+		let documents:Vec<Value> = stream.read_from(&mut buffer).into_iter().map(|document| document.unwrap()).collect();
+		assert_eq!(documents, vec![serde_json::json!({ "a": 1 })]);
+		assert_eq!(stream.pending_len(), 5);
+
+		buffer.extend(b"2}\n[3]\n");
+		let documents:Vec<Value> = stream.read_from(&mut buffer).into_iter().map(|document| document.unwrap()).collect();
+		assert_eq!(documents, vec![serde_json::json!({ "b": 2 }), serde_json::json!([3])]);
+		assert_eq!(stream.pending_len(), 0);
+	}
+
+	#[test]
+	fn test_json_stream_skips_invalid_lines() {
+		let mut buffer:CircularBufferMultiRead<u8, 64, 1> = CircularBufferMultiRead::new();
+		let cursor:ReadCursor = buffer.create_read_cursor();
+		let mut stream:JsonStream<u32> = JsonStream::default();
+		buffer.extend(b"1\n{oops}\n2\n3");
+
+		let results:Vec<Result<u32, serde_json::Error>> = stream.read_from(&mut CursorReader::new(&mut buffer, cursor));
+		assert_eq!(results.len(), 3);
+		assert_eq!(results[0].as_ref().unwrap(), &1);
+		assert!(results[1].is_err());
+		assert_eq!(results[2].as_ref().unwrap(), &2);
+		assert_eq!(stream.pending_len(), 1);
+	}
+
+	#[test]
+	fn test_json_stream_leaves_incomplete_documents_in_buffer() {
+		let mut buffer:CircularBuffer<u8, 32> = CircularBuffer::new();
+		let mut stream:JsonStream<Value> = JsonStream::new();
+		buffer.extend(b"[1]\n{\"key\":");
+		assert_eq!(stream.read_from(&mut buffer).len(), 1);
+		assert_eq!(buffer.as_slices()[0], b"{\"key\":");
+		assert_eq!(stream.pending_len(), buffer.len());
+	}
+
+	#[test]
+	fn test_json_stream_parses_across_wrap() {
+		let mut buffer:CircularBuffer<u8, 16> = CircularBuffer::new();
+		let mut stream:JsonStream<u32> = JsonStream::new();
+		buffer.extend(b"1\n2\n3\n4\n5\n6\n");
+		assert_eq!(stream.read_from(&mut buffer).len(), 6);
+
+		// The first number ends before the wrap, while the second number starts before the wrap and ends after it.
+		buffer.extend(b"10\n1234\n");
+		assert!(!buffer.as_slices()[1].is_empty());
+		let results:Vec<u32> = stream.read_from(&mut buffer).into_iter().map(|document| document.unwrap()).collect();
+		assert_eq!(results, vec![10, 1234]);
+		assert!(buffer.is_empty());
+		assert_eq!(stream.pending_len(), 0);
+	}
+
+	#[test]
+	fn test_json_stream_skips_invalid_line_across_wrap() {
+		let mut buffer:CircularBuffer<u8, 16> = CircularBuffer::new();
+		let mut stream:JsonStream<u32> = JsonStream::new();
+		buffer.extend(b"1\n2\n3\n4\n5\n6\n");
+		stream.read_from(&mut buffer);
+
+		buffer.extend(b"x\"oops\n7\n");
+		let results:Vec<Result<u32, serde_json::Error>> = stream.read_from(&mut buffer);
+		assert_eq!(results.len(), 2);
+		assert!(results[0].is_err());
+		assert_eq!(results[1].as_ref().unwrap(), &7);
+	}
+
+	#[test]
+	fn test_json_stream_reports_unterminated_invalid_line_once() {
+		let mut buffer:CircularBuffer<u8, 32> = CircularBuffer::new();
+		let mut stream:JsonStream<u32> = JsonStream::new();
+		buffer.extend(b"1\n{oops");
+		let results:Vec<Result<u32, serde_json::Error>> = stream.read_from(&mut buffer);
+		assert_eq!(results.len(), 1);
+		assert_eq!(stream.pending_len(), 5);
+
+		buffer.extend(b" more}\n2\n");
+		let results:Vec<Result<u32, serde_json::Error>> = stream.read_from(&mut buffer);
+		assert_eq!(results.len(), 2);
+		assert!(results[0].is_err());
+		assert_eq!(results[1].as_ref().unwrap(), &2);
+		assert_eq!(stream.pending_len(), 0);
+	}
+
+	#[test]
+	fn test_json_stream_drops_invalid_line_filling_buffer() {
+		let mut buffer:CircularBuffer<u8, 8> = CircularBuffer::new();
+		let mut stream:JsonStream<u32> = JsonStream::new();
+		buffer.extend(b"{oops oops");
+		assert!(buffer.is_full());
+		let results:Vec<Result<u32, serde_json::Error>> = stream.read_from(&mut buffer);
+		assert_eq!(results.len(), 1);
+		assert!(results[0].is_err());
+		assert!(buffer.is_empty());
+	}
+
+	#[test]
+	fn test_json_stream_continues_after_crossing_document() {
+		let mut buffer:CircularBuffer<u8, 16> = CircularBuffer::new();
+		let mut stream:JsonStream<Value> = JsonStream::new();
+		buffer.extend(b"1\n2\n3\n4\n5\n6\n");
+		stream.read_from(&mut buffer);
+
+		// The array starts before the wrap and ends after it, followed by more documents after the wrap.
+		buffer.extend(b"[1,\n2]\n3\n4\n");
+		let results:Vec<Value> = stream.read_from(&mut buffer).into_iter().map(|document| document.unwrap()).collect();
+		assert_eq!(results, vec![serde_json::json!([1, 2]), serde_json::json!(3), serde_json::json!(4)]);
+		assert!(buffer.is_empty());
+	}
+}
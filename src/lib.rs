@@ -1,17 +1,128 @@
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
 pub struct ReadCursor(usize);
 
 
 
+mod aligned_storage;
+mod aligned_storage_u;
+mod arena_storage;
+mod arena_storage_u;
+mod buffer_reader;
+mod buffer_reader_u;
+#[cfg(feature = "statistics")]
+mod buffer_statistics;
+mod buffer_statistics_u;
+mod buffer_traits;
+mod bulk_copy;
+mod bulk_copy_u;
+mod chain;
+mod chain_u;
+#[cfg(feature = "checksum")]
+mod checksum;
+mod checksum_u;
 mod circular_buffer;
 mod circular_buffer_u;
+mod circular_buffer_concurrent;
+mod circular_buffer_concurrent_u;
+mod circular_buffer_core;
+mod circular_buffer_core_u;
 mod circular_buffer_dyn;
 mod circular_buffer_dyn_u;
 mod circular_buffer_multi_read;
 mod circular_buffer_multi_read_u;
 mod circular_buffer_multi_read_dyn;
 mod circular_buffer_multi_read_dyn_u;
+mod circular_buffer_sized;
+mod circular_buffer_sized_u;
+mod clock;
+mod clock_u;
+#[cfg(feature = "cobs")]
+mod cobs;
+mod cobs_u;
+mod cursor_reader;
+mod cursor_reader_u;
+#[cfg(feature = "statistics")]
+mod cursor_statistics;
+mod cursor_statistics_u;
+mod downsample;
+mod downsample_u;
+#[cfg(feature = "embedded-io")]
+mod embedded_io_traits;
+mod embedded_io_traits_u;
+#[cfg(feature = "test-util")]
+mod fault_injection;
+mod fault_injection_u;
+#[cfg(feature = "json")]
+mod json_stream;
+mod json_stream_u;
+mod mailbox;
+mod mailbox_u;
+mod merge_by;
+mod merge_by_u;
+mod operation_log;
+mod operation_log_u;
+mod recycler;
+mod recycler_u;
+#[cfg(feature = "registry")]
+pub mod registry;
+mod registry_u;
+mod seq_lock_snapshot;
+mod seq_lock_snapshot_u;
+mod sorted_search;
+mod sorted_search_u;
+mod splitter;
+mod splitter_u;
+mod string_ring;
+mod string_ring_u;
+mod sync_circular_buffer;
+mod sync_circular_buffer_u;
+mod tee;
+mod tee_u;
+mod wait_strategy;
+mod wait_strategy_u;
+#[cfg(feature = "dsp")]
+mod window_function;
+mod window_function_u;
+mod zip;
+mod zip_u;
 
+pub use aligned_storage::*;
+pub use arena_storage::*;
+pub use buffer_reader::*;
+#[cfg(feature = "statistics")]
+pub use buffer_statistics::*;
+pub use buffer_traits::*;
+pub use chain::*;
+#[cfg(feature = "checksum")]
+pub use checksum::*;
 pub use circular_buffer::*;
+pub use circular_buffer_concurrent::*;
+pub use circular_buffer_core::*;
 pub use circular_buffer_dyn::*;
 pub use circular_buffer_multi_read::*;
-pub use circular_buffer_multi_read_dyn::*;
\ No newline at end of file
+pub use circular_buffer_multi_read_dyn::*;
+pub use circular_buffer_sized::*;
+pub use clock::*;
+#[cfg(feature = "cobs")]
+pub use cobs::InvalidCobsFrame;
+pub use cursor_reader::*;
+#[cfg(feature = "statistics")]
+pub use cursor_statistics::*;
+#[cfg(feature = "test-util")]
+pub use fault_injection::*;
+#[cfg(feature = "json")]
+pub use json_stream::*;
+pub use mailbox::*;
+pub use merge_by::*;
+pub use operation_log::*;
+pub use recycler::*;
+pub use seq_lock_snapshot::*;
+pub use splitter::*;
+pub use string_ring::*;
+pub use sync_circular_buffer::*;
+pub use tee::*;
+pub use wait_strategy::*;
+#[cfg(feature = "dsp")]
+pub use window_function::*;
+pub use zip::*;
\ No newline at end of file
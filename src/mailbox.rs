@@ -0,0 +1,127 @@
+use std::{ cell::UnsafeCell, mem::MaybeUninit, sync::atomic::{ AtomicU8, Ordering } };
+
+
+
+/// The flag in the shared slot index that marks the shared slot as holding a message that was not taken yet.
+const NEW_MESSAGE:u8 = 0b100;
+
+
+
+/// Passes only the newest message from one thread to another, like the latest control message for a realtime thread.
+/// Posting replaces an unread message, taking empties the mailbox. Split the mailbox into a sender and a receiver to use it.
+/// The mailbox is a triple buffer: the sender and receiver each own one of three preallocated slots and exchange it with the shared slot using a single atomic swap, so neither side ever waits or allocates.
+pub struct Mailbox<T> {
+	slots:[UnsafeCell<MaybeUninit<T>>; 3],
+	shared_slot:AtomicU8
+}
+impl<T> Mailbox<T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new empty mailbox.
+	pub const fn new() -> Mailbox<T> {
+		Mailbox {
+			slots: [const { UnsafeCell::new(MaybeUninit::uninit()) }; 3],
+			shared_slot: AtomicU8::new(0)
+		}
+	}
+
+	/// Split the mailbox into the sender that posts messages and the receiver that takes them.
+	pub fn split(&mut self) -> (MailboxSender<'_, T>, MailboxReceiver<'_, T>) {
+
+		// Only the shared slot can hold a message between posts and takes, so the other two slots are free to hand out.
+		let shared_slot:usize = (*self.shared_slot.get_mut() & !NEW_MESSAGE) as usize;
+		let mailbox:&Mailbox<T> = self;
+		(
+			MailboxSender { mailbox, owned_slot: (shared_slot + 1) % 3 },
+			MailboxReceiver { mailbox, owned_slot: (shared_slot + 2) % 3 }
+		)
+	}
+
+
+
+	/* MESSAGE METHODS */
+
+	/// Wether or not a message is waiting to be taken.
+	pub fn has_message(&self) -> bool {
+		self.shared_slot.load(Ordering::Acquire) & NEW_MESSAGE != 0
+	}
+
+	/// Put the owned slot in the shared position and return the slot that was shared, including its new-message flag.
+	fn exchange(&self, owned_slot:usize, flags:u8) -> u8 {
+		self.shared_slot.swap(owned_slot as u8 | flags, Ordering::AcqRel)
+	}
+
+	/// Move the message out of a slot. The slot needs to hold a message, which it no longer does afterwards.
+	unsafe fn read_slot(&self, slot:usize) -> T {
+		unsafe { (*self.slots[slot].get()).assume_init_read() }
+	}
+}
+impl<T> Default for Mailbox<T> {
+	fn default() -> Mailbox<T> {
+		Mailbox::new()
+	}
+}
+impl<T> Drop for Mailbox<T> {
+	fn drop(&mut self) {
+		let shared_slot:u8 = *self.shared_slot.get_mut();
+		if shared_slot & NEW_MESSAGE != 0 {
+			drop(unsafe { self.read_slot((shared_slot & !NEW_MESSAGE) as usize) });
+		}
+	}
+}
+unsafe impl<T:Send> Sync for Mailbox<T> {}
+
+
+
+/// The sending side of a Mailbox.
+pub struct MailboxSender<'a, T> {
+	mailbox:&'a Mailbox<T>,
+	owned_slot:usize
+}
+impl<T> MailboxSender<'_, T> {
+
+	/// Post a message, replacing the current message. Returns the replaced message if it was not taken yet.
+	pub fn post(&mut self, message:T) -> Option<T> {
+		unsafe { (*self.mailbox.slots[self.owned_slot].get()).write(message) };
+		let shared_slot:u8 = self.mailbox.exchange(self.owned_slot, NEW_MESSAGE);
+		self.owned_slot = (shared_slot & !NEW_MESSAGE) as usize;
+		if shared_slot & NEW_MESSAGE != 0 {
+			Some(unsafe { self.mailbox.read_slot(self.owned_slot) })
+		} else {
+			None
+		}
+	}
+
+	/// Wether or not the last posted message is still waiting to be taken.
+	pub fn has_message(&self) -> bool {
+		self.mailbox.has_message()
+	}
+}
+
+
+
+/// The receiving side of a Mailbox.
+pub struct MailboxReceiver<'a, T> {
+	mailbox:&'a Mailbox<T>,
+	owned_slot:usize
+}
+impl<T> MailboxReceiver<'_, T> {
+
+	/// Take the newest message, leaving the mailbox empty. Returns None if no message was posted since the last take.
+	pub fn take(&mut self) -> Option<T> {
+		if !self.mailbox.has_message() {
+			return None;
+		}
+
+		// Only the receiver clears the new-message flag, so the swapped out slot always holds the message.
+		let shared_slot:u8 = self.mailbox.exchange(self.owned_slot, 0);
+		self.owned_slot = (shared_slot & !NEW_MESSAGE) as usize;
+		Some(unsafe { self.mailbox.read_slot(self.owned_slot) })
+	}
+
+	/// Wether or not a message is waiting to be taken.
+	pub fn has_message(&self) -> bool {
+		self.mailbox.has_message()
+	}
+}
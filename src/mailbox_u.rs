@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Mailbox, MailboxReceiver, MailboxSender };
+	use std::{ rc::Rc, thread };
+
+
+
+	#[test]
+	fn test_post_replaces_and_take_empties() {
+		let mut mailbox:Mailbox<String> = Mailbox::new();
+		let (mut sender, mut receiver) = mailbox.split();
+		assert!(!receiver.has_message());
+		assert_eq!(receiver.take(), None);
+
+		assert_eq!(sender.post("first".to_string()), None);
+		assert_eq!(sender.post("second".to_string()), Some("first".to_string()));
+		assert!(receiver.has_message());
+		assert_eq!(receiver.take(), Some("second".to_string()));
+		assert_eq!(receiver.take(), None);
+		assert_eq!(sender.post("third".to_string()), None);
+		assert!(sender.has_message());
+	}
+
+	#[test]
+	fn test_split_again_keeps_unread_message() {
+		let mut mailbox:Mailbox<i32> = Mailbox::new();
+		{
+			let (mut sender, mut receiver) = mailbox.split();
+			sender.post(1);
+			assert_eq!(receiver.take(), Some(1));
+			sender.post(2);
+		}
+		assert!(mailbox.has_message());
+		let (mut sender, mut receiver) = mailbox.split();
+		assert_eq!(sender.post(3), Some(2));
+		assert_eq!(receiver.take(), Some(3));
+	}
+
+	#[test]
+	fn test_drop_releases_unread_message() {
+		let message:Rc<i32> = Rc::new(5);
+		{
+			let mut mailbox:Mailbox<Rc<i32>> = Mailbox::new();
+			let (mut sender, _receiver) = mailbox.split();
+			sender.post(message.clone());
+			sender.post(message.clone());
+			assert_eq!(Rc::strong_count(&message), 2);
+		}
+		assert_eq!(Rc::strong_count(&message), 1);
+	}
+
+	#[test]
+	fn test_concurrent_takes_see_newer_messages() {
+		let mut mailbox:Mailbox<u64> = Mailbox::default();
+		let (mut sender, mut receiver):(MailboxSender<u64>, MailboxReceiver<u64>) = mailbox.split();
+		thread::scope(|scope| {
+			scope.spawn(move || {
+				let mut last_message:u64 = 0;
+				while last_message != 10_000 {
+					if let Some(message) = receiver.take() {
+						assert!(message > last_message);
+						last_message = message;
+					}
+				}
+			});
+			for message in 1..=10_000 {
+				sender.post(message);
+			}
+		});
+		assert!(!mailbox.has_message());
+	}
+}
@@ -0,0 +1,94 @@
+use crate::BufferRead;
+
+
+
+/// Reads from multiple buffers and yields the samples in order of a key, for example to fuse multiple sensor streams into a single chronologically ordered stream.
+/// Each source should be sorted by the key itself. One sample of each source is held in the merger until it is yielded.
+/// Buffers with multiple read cursors are added by wrapping them in a CursorReader, which reads using one cursor and hands the cursor back using into_cursor once the merger is dropped.
+pub struct MergeBy<'a, T, K:Ord, F:FnMut(&T) -> K> {
+	sources:Vec<&'a mut dyn BufferRead<T>>,
+	heads:Vec<Option<T>>,
+	key:F
+}
+impl<'a, T:Default, K:Ord, F:FnMut(&T) -> K> MergeBy<'a, T, K, F> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new merger without any sources. The key function returns the value samples are sorted by.
+	pub fn new(key:F) -> MergeBy<'a, T, K, F> {
+		MergeBy {
+			sources: Vec::new(),
+			heads: Vec::new(),
+			key
+		}
+	}
+
+	/// Add a source buffer. Returns the index of the source.
+	/// To read from a buffer with multiple read cursors, add a CursorReader of the buffer and cursor as the source.
+	pub fn add_source<B:BufferRead<T>>(&mut self, source:&'a mut B) -> usize {
+		self.sources.push(source);
+		self.heads.push(None);
+		self.sources.len() - 1
+	}
+
+
+
+	/* BUFFER METHODS */
+
+	/// Take the sample with the lowest key of all currently available samples. When keys are equal, the source added first goes first.
+	pub fn take_one(&mut self) -> Option<T> {
+
+		// Make sure every source with available data has a head sample.
+		for (source, head) in self.sources.iter_mut().zip(&mut self.heads) {
+			if head.is_none() {
+				let mut sample:[T; 1] = [T::default()];
+				if source.take_to_buffer(&mut sample) == 1 {
+					let [sample] = sample;
+					*head = Some(sample);
+				}
+			}
+		}
+
+		// Take the head with the lowest key.
+		let mut lowest:Option<(usize, K)> = None;
+		for (head_index, head) in self.heads.iter().enumerate() {
+			if let Some(sample) = head {
+				let sample_key:K = (self.key)(sample);
+				if lowest.as_ref().map(|(_, lowest_key)| sample_key < *lowest_key).unwrap_or(true) {
+					lowest = Some((head_index, sample_key));
+				}
+			}
+		}
+		lowest.and_then(|(head_index, _)| self.heads[head_index].take())
+	}
+
+	/// Take all currently available samples in order.
+	pub fn take_all(&mut self) -> Vec<T> {
+		let mut output:Vec<T> = Vec::new();
+		while let Some(sample) = self.take_one() {
+			output.push(sample);
+		}
+		output
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Return the amount of samples available in all sources, including the samples held by the merger.
+	pub fn len(&self) -> usize {
+		self.sources.iter().map(|source| source.len()).sum::<usize>() + self.heads.iter().filter(|head| head.is_some()).count()
+	}
+
+	/// Wether or not there are 0 available samples.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+impl<T:Default, K:Ord, F:FnMut(&T) -> K> Iterator for MergeBy<'_, T, K, F> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<T> {
+		self.take_one()
+	}
+}
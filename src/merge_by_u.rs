@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ CircularBuffer, CircularBufferDyn, CircularBufferMultiReadDyn, CursorReader, MergeBy, ReadCursor };
+
+
+
+	#[test]
+	fn test_merge_by_key() {
+		let mut sensor_a:CircularBuffer<(u32, char), 8> = CircularBuffer::new();
+		let mut sensor_b:CircularBufferDyn<(u32, char)> = CircularBufferDyn::new(8);
+		sensor_a.extend(&[(1, 'a'), (4, 'a'), (5, 'a')]);
+		sensor_b.extend(&[(2, 'b'), (3, 'b'), (6, 'b')]);
+
+		let mut merger = MergeBy::new(|sample:&(u32, char)| sample.0);
+		merger.add_source(&mut sensor_a);
+		merger.add_source(&mut sensor_b);
+		assert_eq!(merger.len(), 6);
+		assert_eq!(merger.take_all(), vec![(1, 'a'), (2, 'b'), (3, 'b'), (4, 'a'), (5, 'a'), (6, 'b')]);
+		assert!(merger.is_empty());
+	}
+
+	#[test]
+	fn test_merge_by_equal_keys_keep_source_order() {
+		let mut source_a:CircularBuffer<(u32, char), 8> = CircularBuffer::new();
+		let mut source_b:CircularBuffer<(u32, char), 8> = CircularBuffer::new();
+		source_a.extend(&[(1, 'a'), (2, 'a')]);
+		source_b.extend(&[(1, 'b'), (2, 'b')]);
+
+		let mut merger = MergeBy::new(|sample:&(u32, char)| sample.0);
+		merger.add_source(&mut source_b);
+		merger.add_source(&mut source_a);
+		assert_eq!(merger.collect::<Vec<(u32, char)>>(), vec![(1, 'b'), (1, 'a'), (2, 'b'), (2, 'a')]);
+	}
+
+	#[test]
+	fn test_merge_by_holds_head_until_yielded() {
+		let mut source_a:CircularBuffer<u32, 8> = CircularBuffer::new();
+		let mut source_b:CircularBuffer<u32, 8> = CircularBuffer::new();
+		source_a.extend(&[1, 5]);
+		source_b.extend(&[2]);
+
+		let mut merger = MergeBy::new(|sample:&u32| *sample);
+		merger.add_source(&mut source_a);
+		merger.add_source(&mut source_b);
+		assert_eq!(merger.take_one(), Some(1));
+		assert_eq!(merger.take_one(), Some(2));
+		assert_eq!(merger.len(), 1);
+		assert_eq!(merger.take_one(), Some(5));
+		assert_eq!(merger.take_one(), None);
+	}
+
+	#[test]
+	fn test_merge_by_cursor_reader_source() {
+		let mut sensor_a:CircularBufferMultiReadDyn<u32> = CircularBufferMultiReadDyn::new(8);
+		let cursor_a:ReadCursor = sensor_a.create_read_cursor();
+		let logger_cursor:ReadCursor = sensor_a.create_read_cursor();
+		let mut sensor_b:CircularBuffer<u32, 8> = CircularBuffer::new();
+		sensor_a.extend(&[1, 4, 5]);
+		sensor_b.extend(&[2, 3, 6]);
+
+		let mut reader_a:CursorReader<CircularBufferMultiReadDyn<u32>> = CursorReader::new(&mut sensor_a, cursor_a);
+		let mut merger = MergeBy::new(|sample:&u32| *sample);
+		merger.add_source(&mut reader_a);
+		merger.add_source(&mut sensor_b);
+		assert_eq!(merger.len(), 6);
+		assert_eq!(merger.take_all(), vec![1, 2, 3, 4, 5, 6]);
+		drop(merger);
+
+		let cursor_a:ReadCursor = reader_a.into_cursor();
+		assert_eq!(sensor_a.len(&cursor_a), 0);
+		assert_eq!(sensor_a.take_all(&logger_cursor), vec![1, 4, 5]);
+	}
+}
@@ -0,0 +1,232 @@
+use crate::{ BufferRead, BufferWrite, CursorBufferRead, ReadCursor };
+
+
+
+/// A single recorded buffer operation. Only sizes and cursor ids are recorded, not the samples themselves.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Operation {
+	Extend { offered:usize, written:usize },
+	Take { cursor:Option<usize>, requested:usize, taken:usize },
+	Skip { cursor:usize, requested:usize, skipped:usize }
+}
+
+
+
+/// A trace of buffer operations, recorded by a Recorder. Can be stored in a compact binary form and replayed against a fresh buffer, which makes problems in buffer-driven pipelines reproducible.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct OperationLog {
+	operations:Vec<Operation>
+}
+impl OperationLog {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new empty log.
+	pub fn new() -> OperationLog {
+		OperationLog { operations: Vec::new() }
+	}
+
+	/// Read a log from its compact binary form. Returns None if the data is not a valid log.
+	pub fn from_bytes(bytes:&[u8]) -> Option<OperationLog> {
+		let mut operations:Vec<Operation> = Vec::new();
+		let mut position:usize = 0;
+		while position < bytes.len() {
+			let tag:u8 = bytes[position];
+			position += 1;
+			let mut next = || read_varint(bytes, &mut position);
+			operations.push(match tag {
+				0 => Operation::Extend { offered: next()?, written: next()? },
+				1 => Operation::Take { cursor: None, requested: next()?, taken: next()? },
+				2 => Operation::Take { cursor: Some(next()?), requested: next()?, taken: next()? },
+				3 => Operation::Skip { cursor: next()?, requested: next()?, skipped: next()? },
+				_ => return None
+			});
+		}
+		Some(OperationLog { operations })
+	}
+
+
+
+	/* LOG METHODS */
+
+	/// Add an operation to the log.
+	pub fn record(&mut self, operation:Operation) {
+		self.operations.push(operation);
+	}
+
+	/// Get the compact binary form of the log.
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut bytes:Vec<u8> = Vec::new();
+		for operation in &self.operations {
+			let (tag, values):(u8, [Option<usize>; 3]) = match *operation {
+				Operation::Extend { offered, written } => (0, [Some(offered), Some(written), None]),
+				Operation::Take { cursor: None, requested, taken } => (1, [Some(requested), Some(taken), None]),
+				Operation::Take { cursor: Some(cursor), requested, taken } => (2, [Some(cursor), Some(requested), Some(taken)]),
+				Operation::Skip { cursor, requested, skipped } => (3, [Some(cursor), Some(requested), Some(skipped)])
+			};
+			bytes.push(tag);
+			for value in values.into_iter().flatten() {
+				write_varint(&mut bytes, value);
+			}
+		}
+		bytes
+	}
+
+	/// Replay the log against a buffer with a single reader, using default samples. Returns the index of the first operation with a different result than recorded, if any.
+	/// Cursor operations can not be replayed against a buffer with a single reader and always differ.
+	pub fn replay<T:Default + Clone, B:BufferWrite<T> + BufferRead<T>>(&self, buffer:&mut B) -> Result<(), usize> {
+		for (operation_index, operation) in self.operations.iter().enumerate() {
+			let matches:bool = match *operation {
+				Operation::Extend { offered, written } => buffer.extend(&vec![T::default(); offered]) == written,
+				Operation::Take { cursor: None, requested, taken } => buffer.take_to_buffer(&mut vec![T::default(); requested]) == taken,
+				_ => false
+			};
+			if !matches {
+				return Err(operation_index);
+			}
+		}
+		Ok(())
+	}
+
+	/// Replay the log against a buffer with multiple read cursors, using default samples. Returns the index of the first operation with a different result than recorded, if any.
+	/// The cursors need to be created in the same order as in the recorded buffer. Takes without a cursor can not be replayed and always differ.
+	pub fn replay_with_cursors<T:Default + Clone, B:BufferWrite<T> + CursorBufferRead<T>>(&self, buffer:&mut B) -> Result<(), usize> {
+		for (operation_index, operation) in self.operations.iter().enumerate() {
+			let matches:bool = match *operation {
+				Operation::Extend { offered, written } => buffer.extend(&vec![T::default(); offered]) == written,
+				Operation::Take { cursor: Some(cursor), requested, taken } => buffer.take_to_buffer(&mut vec![T::default(); requested], &ReadCursor(cursor)) == taken,
+				Operation::Skip { cursor, requested, skipped } => buffer.consume(requested, &ReadCursor(cursor)) == skipped,
+				_ => false
+			};
+			if !matches {
+				return Err(operation_index);
+			}
+		}
+		Ok(())
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get all recorded operations, the oldest first.
+	pub fn operations(&self) -> &[Operation] {
+		&self.operations
+	}
+
+	/// Get the amount of recorded operations.
+	pub fn len(&self) -> usize {
+		self.operations.len()
+	}
+
+	/// Wether or not no operations are recorded.
+	pub fn is_empty(&self) -> bool {
+		self.operations.is_empty()
+	}
+}
+
+
+
+/// Wraps a buffer and records every extend, take and skip to an OperationLog.
+/// Operations done directly on the inner buffer are not recorded.
+pub struct Recorder<B> {
+	buffer:B,
+	log:OperationLog
+}
+impl<B> Recorder<B> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new recorder around the given buffer, with an empty log.
+	pub fn new(buffer:B) -> Recorder<B> {
+		Recorder { buffer, log: OperationLog::new() }
+	}
+
+	/// Get back the buffer and the recorded log.
+	pub fn into_inner(self) -> (B, OperationLog) {
+		(self.buffer, self.log)
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get a reference to the buffer.
+	pub fn buffer(&self) -> &B {
+		&self.buffer
+	}
+
+	/// Get a mutable reference to the buffer. Operations done using this reference are not recorded.
+	pub fn buffer_mut(&mut self) -> &mut B {
+		&mut self.buffer
+	}
+
+	/// Get the recorded log.
+	pub fn log(&self) -> &OperationLog {
+		&self.log
+	}
+}
+impl<T, B:BufferWrite<T>> BufferWrite<T> for Recorder<B> {
+	fn extend(&mut self, input:&[T]) -> usize {
+		let written:usize = self.buffer.extend(input);
+		self.log.record(Operation::Extend { offered: input.len(), written });
+		written
+	}
+}
+impl<T, B:BufferRead<T>> BufferRead<T> for Recorder<B> {
+	fn len(&self) -> usize {
+		self.buffer.len()
+	}
+	fn take_to_buffer(&mut self, output:&mut [T]) -> usize {
+		let taken:usize = self.buffer.take_to_buffer(output);
+		self.log.record(Operation::Take { cursor: None, requested: output.len(), taken });
+		taken
+	}
+}
+impl<T, B:CursorBufferRead<T>> CursorBufferRead<T> for Recorder<B> {
+	fn len(&self, cursor:&ReadCursor) -> usize {
+		self.buffer.len(cursor)
+	}
+	fn take_to_buffer(&mut self, output:&mut [T], cursor:&ReadCursor) -> usize {
+		let taken:usize = self.buffer.take_to_buffer(output, cursor);
+		self.log.record(Operation::Take { cursor: Some(cursor.0), requested: output.len(), taken });
+		taken
+	}
+	fn peek_contiguous(&self, cursor:&ReadCursor) -> &[T] {
+		self.buffer.peek_contiguous(cursor)
+	}
+	fn consume(&mut self, amount:usize, cursor:&ReadCursor) -> usize {
+		let skipped:usize = self.buffer.consume(amount, cursor);
+		self.log.record(Operation::Skip { cursor: cursor.0, requested: amount, skipped });
+		skipped
+	}
+}
+
+
+
+/// Write a value using 7 bits per byte, with the highest bit set on all bytes but the last.
+fn write_varint(bytes:&mut Vec<u8>, mut value:usize) {
+	while value >= 0x80 {
+		bytes.push((value as u8 & 0x7F) | 0x80);
+		value >>= 7;
+	}
+	bytes.push(value as u8);
+}
+
+/// Read a value written by write_varint, advancing the position. Returns None if the data ends early or the value does not fit.
+fn read_varint(bytes:&[u8], position:&mut usize) -> Option<usize> {
+	let mut value:usize = 0;
+	let mut shift:u32 = 0;
+	loop {
+		let byte:u8 = *bytes.get(*position)?;
+		*position += 1;
+		if shift >= usize::BITS {
+			return None;
+		}
+		value |= ((byte & 0x7F) as usize).checked_shl(shift)?;
+		if byte & 0x80 == 0 {
+			return Some(value);
+		}
+		shift += 7;
+	}
+}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ BufferRead, BufferWrite, CircularBuffer, CircularBufferMultiReadDyn, CursorBufferRead, Operation, OperationLog, ReadCursor, Recorder };
+
+
+
+	#[test]
+	fn test_record_and_replay() {
+		let mut recorder:Recorder<CircularBuffer<i32, 8>> = Recorder::new(CircularBuffer::new());
+		recorder.extend(&[1, 2, 3, 4, 5]);
+		assert_eq!(recorder.take(2), vec![1, 2]);
+		recorder.extend(&[6, 7, 8, 9, 10, 11]);
+		recorder.take_all();
+		let (_, log) = recorder.into_inner();
+
+		assert_eq!(log.operations(), &[
+			Operation::Extend { offered: 5, written: 5 },
+			Operation::Take { cursor: None, requested: 2, taken: 2 },
+			Operation::Extend { offered: 6, written: 4 },
+			Operation::Take { cursor: None, requested: 7, taken: 7 }
+		]);
+		assert_eq!(log.replay(&mut CircularBuffer::<i32, 8>::new()), Ok(()));
+		assert_eq!(log.replay(&mut CircularBuffer::<i32, 16>::new()), Err(2));
+	}
+
+	#[test]
+	fn test_record_and_replay_with_cursors() {
+		let mut buffer:CircularBufferMultiReadDyn<u8> = CircularBufferMultiReadDyn::new(8);
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let cursor_b:ReadCursor = buffer.create_read_cursor();
+		let mut recorder:Recorder<&mut CircularBufferMultiReadDyn<u8>> = Recorder::new(&mut buffer);
+		recorder.extend(&[1, 2, 3, 4]);
+		recorder.take_to_buffer(&mut [0; 3], &cursor_a);
+		recorder.consume(10, &cursor_b);
+		recorder.extend(&[5, 6, 7, 8, 9]);
+		let log:OperationLog = recorder.log().clone();
+		assert_eq!(log.len(), 4);
+
+		let mut fresh_buffer:CircularBufferMultiReadDyn<u8> = CircularBufferMultiReadDyn::new(8);
+		fresh_buffer.create_read_cursor();
+		fresh_buffer.create_read_cursor();
+		assert_eq!(log.replay_with_cursors(&mut fresh_buffer), Ok(()));
+		assert_eq!(log.replay(&mut CircularBuffer::<u8, 8>::new()), Err(1));
+	}
+
+	#[test]
+	fn test_log_bytes_round_trip() {
+		let mut log:OperationLog = OperationLog::new();
+		log.record(Operation::Extend { offered: 300, written: 127 });
+		log.record(Operation::Take { cursor: None, requested: 128, taken: 0 });
+		log.record(Operation::Take { cursor: Some(2), requested: 1, taken: 1 });
+		log.record(Operation::Skip { cursor: 1, requested: usize::MAX, skipped: 5 });
+
+		let bytes:Vec<u8> = log.to_bytes();
+		assert_eq!(bytes[..5], [0, 0xAC, 0x02, 0x7F, 1]);
+		assert_eq!(OperationLog::from_bytes(&bytes), Some(log));
+		assert_eq!(OperationLog::from_bytes(&[0, 0x80]), None);
+		assert_eq!(OperationLog::from_bytes(&[9]), None);
+		assert!(OperationLog::from_bytes(&[]).unwrap().is_empty());
+	}
+}
@@ -0,0 +1,245 @@
+use std::{ cell::UnsafeCell, collections::VecDeque, mem::MaybeUninit, sync::{ Arc, atomic::{ AtomicUsize, Ordering } } };
+
+
+
+/// Lets a consumer return processed objects, like boxed sample buffers or frames, to the producer for reuse.
+/// At most the capacity of the recycler in objects is ever created, which bounds the allocations in a streaming pipeline.
+/// To return objects from another thread, split the recycler into a RecyclerProducer and a RecyclerConsumer, which pass returned objects through a lock-free ring.
+pub struct Recycler<T> {
+	returned:VecDeque<T>,
+	capacity:usize,
+	created_count:usize
+}
+impl<T> Recycler<T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new recycler that creates at most the given amount of objects.
+	pub fn new(capacity:usize) -> Recycler<T> {
+		Recycler {
+			returned: VecDeque::with_capacity(capacity),
+			capacity,
+			created_count: 0
+		}
+	}
+
+
+
+	/// Split the recycler into a producer acquiring objects and a consumer returning them, which can be moved to different threads. Objects already returned stay available to the producer.
+	pub fn split(self) -> (RecyclerProducer<T>, RecyclerConsumer<T>) {
+		let ring:Arc<RecyclerRing<T>> = Arc::new(RecyclerRing::new(self.capacity, self.created_count));
+		for object in self.returned {
+			ring.push(object);
+		}
+		(RecyclerProducer { ring: Arc::clone(&ring) }, RecyclerConsumer { ring })
+	}
+
+
+
+	/* RECYCLING METHODS */
+
+	/// Get an object to fill. Reuses the oldest returned object if any, otherwise creates a new one using the create function.
+	/// Returns None if all objects are in use and no more may be created.
+	pub fn acquire<F:FnOnce() -> T>(&mut self, create:F) -> Option<T> {
+		if let Some(object) = self.returned.pop_front() {
+			return Some(object);
+		}
+		if self.created_count < self.capacity {
+			self.created_count += 1;
+			return Some(create());
+		}
+		None
+	}
+
+	/// Return a processed object for reuse. Returns false if the recycler already holds its capacity in objects, in which case the object is dropped.
+	pub fn release(&mut self, object:T) -> bool {
+		if self.returned.len() >= self.capacity {
+			return false;
+		}
+		self.returned.push_back(object);
+		true
+	}
+
+	/// Drop a processed object instead of returning it, allowing a new object to be created in its place.
+	pub fn discard(&mut self, object:T) {
+		drop(object);
+		self.created_count = self.created_count.saturating_sub(1);
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the maximum amount of objects the recycler creates.
+	pub fn capacity(&self) -> usize {
+		self.capacity
+	}
+
+	/// Get the amount of objects created so far.
+	pub fn created_count(&self) -> usize {
+		self.created_count
+	}
+
+	/// Get the amount of returned objects waiting for reuse.
+	pub fn available_count(&self) -> usize {
+		self.returned.len()
+	}
+
+	/// Get the total amount of bytes held by the recycler, not counting memory held by the returned objects themselves.
+	pub fn memory_usage(&self) -> usize {
+		size_of::<Recycler<T>>() + self.returned.capacity() * size_of::<T>()
+	}
+
+	/// Get the amount of created objects that are currently in use.
+	pub fn in_use_count(&self) -> usize {
+		self.created_count.saturating_sub(self.returned.len())
+	}
+}
+
+
+
+/// The ring returned objects travel through from a RecyclerConsumer to a RecyclerProducer. One slot is always kept empty, like in the other buffers.
+struct RecyclerRing<T> {
+	slots:Box<[UnsafeCell<MaybeUninit<T>>]>,
+	read_cursor:AtomicUsize,
+	write_cursor:AtomicUsize,
+	created_count:AtomicUsize,
+	capacity:usize
+}
+impl<T> RecyclerRing<T> {
+
+	/// Create a new ring that can hold the given amount of returned objects.
+	fn new(capacity:usize, created_count:usize) -> RecyclerRing<T> {
+		RecyclerRing {
+			slots: (0..capacity + 1).map(|_| UnsafeCell::new(MaybeUninit::uninit())).collect(),
+			read_cursor: AtomicUsize::new(0),
+			write_cursor: AtomicUsize::new(0),
+			created_count: AtomicUsize::new(created_count),
+			capacity
+		}
+	}
+
+	/// Add a returned object. Only called by the consumer. Returns false if the ring is full, in which case the object is dropped.
+	fn push(&self, object:T) -> bool {
+		let write_cursor:usize = self.write_cursor.load(Ordering::Relaxed);
+		let next_cursor:usize = (write_cursor + 1) % self.slots.len();
+		if next_cursor == self.read_cursor.load(Ordering::Acquire) {
+			return false;
+		}
+		unsafe { (*self.slots[write_cursor].get()).write(object); }
+		self.write_cursor.store(next_cursor, Ordering::Release);
+		true
+	}
+
+	/// Take the oldest returned object. Only called by the producer.
+	fn pop(&self) -> Option<T> {
+		let read_cursor:usize = self.read_cursor.load(Ordering::Relaxed);
+		if read_cursor == self.write_cursor.load(Ordering::Acquire) {
+			return None;
+		}
+		let object:T = unsafe { (*self.slots[read_cursor].get()).assume_init_read() };
+		self.read_cursor.store((read_cursor + 1) % self.slots.len(), Ordering::Release);
+		Some(object)
+	}
+
+	/// Create a new object if less than the capacity in objects exists.
+	fn create<F:FnOnce() -> T>(&self, create:F) -> Option<T> {
+		self.created_count.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |created_count| (created_count < self.capacity).then_some(created_count + 1)).ok()?;
+		Some(create())
+	}
+
+	/// Drop an object instead of returning it, allowing a new object to be created in its place.
+	fn discard(&self, object:T) {
+		drop(object);
+		let _ = self.created_count.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |created_count| created_count.checked_sub(1));
+	}
+
+	/// Return the amount of returned objects waiting for reuse.
+	fn available_count(&self) -> usize {
+		let read_cursor:usize = self.read_cursor.load(Ordering::Acquire);
+		(self.write_cursor.load(Ordering::Acquire) + self.slots.len() - read_cursor) % self.slots.len()
+	}
+}
+impl<T> Drop for RecyclerRing<T> {
+	fn drop(&mut self) {
+		while self.pop().is_some() {}
+	}
+}
+unsafe impl<T:Send> Send for RecyclerRing<T> {}
+unsafe impl<T:Send> Sync for RecyclerRing<T> {}
+
+
+
+/// The half of a split Recycler that acquires objects to fill, usually owned by the producing thread.
+pub struct RecyclerProducer<T> {
+	ring:Arc<RecyclerRing<T>>
+}
+impl<T> RecyclerProducer<T> {
+
+	/* RECYCLING METHODS */
+
+	/// Get an object to fill. Reuses the oldest returned object if any, otherwise creates a new one using the create function.
+	/// Returns None if all objects are in use and no more may be created.
+	pub fn acquire<F:FnOnce() -> T>(&mut self, create:F) -> Option<T> {
+		self.ring.pop().or_else(|| self.ring.create(create))
+	}
+
+	/// Drop an object instead of filling it, allowing a new object to be created in its place.
+	pub fn discard(&mut self, object:T) {
+		self.ring.discard(object);
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the maximum amount of objects the recycler creates.
+	pub fn capacity(&self) -> usize {
+		self.ring.capacity
+	}
+
+	/// Get the amount of objects created so far.
+	pub fn created_count(&self) -> usize {
+		self.ring.created_count.load(Ordering::Relaxed)
+	}
+
+	/// Get the amount of returned objects waiting for reuse.
+	pub fn available_count(&self) -> usize {
+		self.ring.available_count()
+	}
+}
+
+
+
+/// The half of a split Recycler that returns processed objects, usually owned by the consuming thread.
+pub struct RecyclerConsumer<T> {
+	ring:Arc<RecyclerRing<T>>
+}
+impl<T> RecyclerConsumer<T> {
+
+	/* RECYCLING METHODS */
+
+	/// Return a processed object for reuse. Returns false if the recycler already holds its capacity in objects, in which case the object is dropped.
+	pub fn release(&mut self, object:T) -> bool {
+		self.ring.push(object)
+	}
+
+	/// Drop a processed object instead of returning it, allowing a new object to be created in its place.
+	pub fn discard(&mut self, object:T) {
+		self.ring.discard(object);
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the maximum amount of objects the recycler creates.
+	pub fn capacity(&self) -> usize {
+		self.ring.capacity
+	}
+
+	/// Get the amount of returned objects waiting for reuse.
+	pub fn available_count(&self) -> usize {
+		self.ring.available_count()
+	}
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ CircularBufferSized, Recycler, RecyclerConsumer, RecyclerProducer };
+	use std::{ sync::mpsc, thread };
+
+
+
+	#[test]
+	fn test_recycler_bounds_creation() {
+		let mut recycler:Recycler<Vec<u8>> = Recycler::new(2);
+		let first:Vec<u8> = recycler.acquire(|| vec![0; 4]).unwrap();
+		let second:Vec<u8> = recycler.acquire(|| vec![0; 4]).unwrap();
+		assert!(recycler.acquire(|| vec![0; 4]).is_none());
+		assert_eq!(recycler.created_count(), 2);
+		assert_eq!(recycler.in_use_count(), 2);
+
+		assert!(recycler.release(first));
+		assert_eq!(recycler.available_count(), 1);
+		assert_eq!(recycler.in_use_count(), 1);
+		assert_eq!(recycler.acquire(|| panic!("Should reuse the returned object.")).unwrap().len(), 4);
+
+		recycler.discard(second);
+		assert_eq!(recycler.created_count(), 1);
+		assert!(recycler.acquire(|| vec![1; 2]).is_some());
+	}
+
+	#[test]
+	fn test_recycler_reuses_objects_through_pipeline() {
+		let mut recycler:Recycler<Vec<u8>> = Recycler::new(3);
+		let mut pipeline:CircularBufferSized<Vec<u8>> = CircularBufferSized::new_byte_sized(1024);
+		for frame_index in 0..100u8 {
+			let mut frame:Vec<u8> = recycler.acquire(Vec::new).unwrap();
+			frame.clear();
+			frame.push(frame_index);
+			pipeline.push(frame);
+			if pipeline.len() == 3 {
+				let processed:Vec<u8> = pipeline.take_one().unwrap();
+				assert_eq!(processed[0], frame_index - 2);
+				assert!(recycler.release(processed));
+			}
+		}
+		assert_eq!(recycler.created_count(), 3);
+	}
+
+	#[test]
+	fn test_split_recycler_across_threads() {
+		let mut recycler:Recycler<Vec<u8>> = Recycler::new(4);
+		let returned:Vec<u8> = recycler.acquire(|| vec![0; 16]).unwrap();
+		assert!(recycler.release(returned));
+		let (mut producer, mut consumer):(RecyclerProducer<Vec<u8>>, RecyclerConsumer<Vec<u8>>) = recycler.split();
+		assert_eq!(producer.available_count(), 1);
+
+		let (sender, receiver) = mpsc::sync_channel::<Vec<u8>>(4);
+		let consumer_thread:thread::JoinHandle<usize> = thread::spawn(move || {
+			let mut sum:usize = 0;
+			for frame in receiver {
+				sum += frame[0] as usize;
+				assert!(consumer.release(frame));
+			}
+			sum
+		});
+
+		let mut expected_sum:usize = 0;
+		for frame_index in 0..1000usize {
+			let mut frame:Vec<u8> = loop {
+				if let Some(frame) = producer.acquire(|| vec![0; 16]) {
+					break frame;
+				}
+				thread::yield_now();
+			};
+			frame[0] = (frame_index % 256) as u8;
+			expected_sum += frame_index % 256;
+			sender.send(frame).unwrap();
+		}
+		drop(sender);
+		assert_eq!(consumer_thread.join().unwrap(), expected_sum);
+		assert!(producer.created_count() <= producer.capacity());
+	}
+
+	#[test]
+	fn test_split_recycler_discard() {
+		let (mut producer, mut consumer) = Recycler::<Box<u32>>::new(1).split();
+		let object:Box<u32> = producer.acquire(|| Box::new(1)).unwrap();
+		assert!(producer.acquire(|| Box::new(2)).is_none());
+		consumer.discard(object);
+		assert_eq!(*producer.acquire(|| Box::new(3)).unwrap(), 3);
+		assert!(consumer.release(Box::new(4)));
+		assert!(!consumer.release(Box::new(5)));
+		assert_eq!(consumer.available_count(), 1);
+	}
+}
@@ -0,0 +1,168 @@
+//! A global registry of named buffers, which allows inspecting the occupancy and drop statistics of every registered buffer in the process, for example from a debug endpoint.
+//! Buffers are registered using their register method, after which every write updates their registered state using only atomic stores. Requires the 'registry' feature.
+//! A registration belongs to a single buffer. It is released when the buffer is dropped, and clones of a registered buffer start out unregistered. As dropping releases the slot, buffers are not Copy with the 'registry' feature.
+
+use crate::BufferStatistics;
+use std::sync::{ Mutex, atomic::{ AtomicBool, AtomicUsize, Ordering } };
+
+
+
+/// The maximum amount of buffers that can be registered at the same time.
+pub const MAX_REGISTERED_BUFFERS:usize = 64;
+
+/// The registry slots. A static array keeps registration possible for buffers created in statics.
+static SLOTS:[RegistrySlot; MAX_REGISTERED_BUFFERS] = [const { RegistrySlot::new() }; MAX_REGISTERED_BUFFERS];
+
+
+
+/// The registered state of a single buffer. The name is only locked when registering, unregistering and taking snapshots, so writes to a registered buffer never wait.
+struct RegistrySlot {
+	claimed:AtomicBool,
+	name:Mutex<Option<String>>,
+	occupancy:AtomicUsize,
+	capacity:AtomicUsize,
+	offered_count:AtomicUsize,
+	written_count:AtomicUsize,
+	high_water_mark:AtomicUsize
+}
+impl RegistrySlot {
+
+	/// Create a new unused slot.
+	const fn new() -> RegistrySlot {
+		RegistrySlot {
+			claimed: AtomicBool::new(false),
+			name: Mutex::new(None),
+			occupancy: AtomicUsize::new(0),
+			capacity: AtomicUsize::new(0),
+			offered_count: AtomicUsize::new(0),
+			written_count: AtomicUsize::new(0),
+			high_water_mark: AtomicUsize::new(0)
+		}
+	}
+}
+
+
+
+/// The registration of a buffer, releasing its slot when dropped. Cloning gives an unregistered value, so only one buffer ever updates a slot.
+#[derive(PartialEq, Eq, Debug)]
+pub(crate) struct Registration(Option<usize>);
+impl Registration {
+
+	/// A buffer that is not registered.
+	pub(crate) const NONE:Registration = Registration(None);
+
+	/// Claim a free slot under the given name. Returns an unregistered value if all slots are in use.
+	pub(crate) fn register(name:&str) -> Registration {
+		Registration(SLOTS.iter().position(|slot| {
+			if slot.claimed.compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed).is_err() {
+				return false;
+			}
+			*slot.name.lock().unwrap_or_else(|error| error.into_inner()) = Some(name.to_string());
+			true
+		}))
+	}
+
+	/// Wether or not a slot is claimed.
+	pub(crate) fn is_registered(&self) -> bool {
+		self.0.is_some()
+	}
+
+	/// Update the state of the claimed slot, if any.
+	pub(crate) fn publish(&self, occupancy:usize, capacity:usize, statistics:&BufferStatistics) {
+		let Some(slot_index) = self.0 else {
+			return;
+		};
+		let slot:&RegistrySlot = &SLOTS[slot_index];
+		slot.occupancy.store(occupancy, Ordering::Relaxed);
+		slot.capacity.store(capacity, Ordering::Relaxed);
+		slot.offered_count.store(statistics.offered_count(), Ordering::Relaxed);
+		slot.written_count.store(statistics.written_count(), Ordering::Relaxed);
+		slot.high_water_mark.store(statistics.high_water_mark(), Ordering::Relaxed);
+	}
+}
+impl Clone for Registration {
+	fn clone(&self) -> Registration {
+		Registration::NONE
+	}
+}
+impl Drop for Registration {
+	fn drop(&mut self) {
+		if let Some(slot_index) = self.0.take() {
+			let slot:&RegistrySlot = &SLOTS[slot_index];
+			*slot.name.lock().unwrap_or_else(|error| error.into_inner()) = None;
+			slot.claimed.store(false, Ordering::Release);
+		}
+	}
+}
+
+
+
+/// The state of a registered buffer at the moment of the snapshot.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct BufferSnapshot {
+	name:String,
+	occupancy:usize,
+	capacity:usize,
+	offered_count:usize,
+	written_count:usize,
+	high_water_mark:usize
+}
+impl BufferSnapshot {
+
+	/// Return the name the buffer was registered under.
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Return the amount of unread samples after the last write. For buffers with multiple read cursors, this is the amount for the cursor that is furthest behind.
+	pub fn occupancy(&self) -> usize {
+		self.occupancy
+	}
+
+	/// Return the capacity of the buffer.
+	pub fn capacity(&self) -> usize {
+		self.capacity
+	}
+
+	/// Return the total amount of samples offered to the buffer.
+	pub fn offered_count(&self) -> usize {
+		self.offered_count
+	}
+
+	/// Return the total amount of samples written to the buffer.
+	pub fn written_count(&self) -> usize {
+		self.written_count
+	}
+
+	/// Return the total amount of samples dropped because the buffer was full.
+	pub fn dropped_count(&self) -> usize {
+		self.offered_count.saturating_sub(self.written_count)
+	}
+
+	/// Return the largest amount of samples ever stored at once.
+	pub fn high_water_mark(&self) -> usize {
+		self.high_water_mark
+	}
+}
+
+
+
+/// Get the state of all registered buffers.
+pub fn snapshot() -> Vec<BufferSnapshot> {
+	SLOTS.iter().filter_map(|slot| {
+		let name:String = slot.name.lock().unwrap_or_else(|error| error.into_inner()).clone()?;
+		Some(BufferSnapshot {
+			name,
+			occupancy: slot.occupancy.load(Ordering::Relaxed),
+			capacity: slot.capacity.load(Ordering::Relaxed),
+			offered_count: slot.offered_count.load(Ordering::Relaxed),
+			written_count: slot.written_count.load(Ordering::Relaxed),
+			high_water_mark: slot.high_water_mark.load(Ordering::Relaxed)
+		})
+	}).collect()
+}
+
+/// Return the amount of slots not claimed by any buffer.
+pub fn free_slot_count() -> usize {
+	SLOTS.iter().filter(|slot| !slot.claimed.load(Ordering::Acquire)).count()
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+#[cfg(feature = "registry")]
+mod tests {
+	use crate::{ CircularBuffer, CircularBufferMultiReadDyn, ReadCursor, registry::{ self, BufferSnapshot } };
+
+
+
+	fn find_snapshot(name:&str) -> Option<BufferSnapshot> {
+		registry::snapshot().into_iter().find(|snapshot| snapshot.name() == name)
+	}
+
+	#[test]
+	fn test_registered_buffer_publishes_writes() {
+		let mut buffer:CircularBuffer<i32, 8> = CircularBuffer::new();
+		assert!(buffer.register("registry_test_single"));
+		assert_eq!(find_snapshot("registry_test_single").unwrap().occupancy(), 0);
+
+		buffer.extend(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+		let snapshot:BufferSnapshot = find_snapshot("registry_test_single").unwrap();
+		assert_eq!(snapshot.occupancy(), 7);
+		assert_eq!(snapshot.capacity(), 8);
+		assert_eq!(snapshot.offered_count(), 9);
+		assert_eq!(snapshot.dropped_count(), 2);
+		assert_eq!(snapshot.high_water_mark(), 7);
+
+		buffer.unregister();
+		assert_eq!(find_snapshot("registry_test_single"), None);
+	}
+
+	#[test]
+	fn test_registered_multi_read_buffer() {
+		let mut buffer:CircularBufferMultiReadDyn<i32> = CircularBufferMultiReadDyn::new(16);
+		let cursor_a:ReadCursor = buffer.create_read_cursor();
+		let _cursor_b:ReadCursor = buffer.create_read_cursor();
+		assert!(buffer.register("registry_test_multi"));
+		buffer.extend(&[1, 2, 3]);
+		buffer.take_all(&cursor_a);
+		buffer.extend(&[4]);
+		assert_eq!(find_snapshot("registry_test_multi").unwrap().occupancy(), 4);
+		buffer.unregister();
+	}
+
+	#[test]
+	fn test_dropping_buffer_releases_registration() {
+		let mut buffer:CircularBuffer<i32, 8> = CircularBuffer::new();
+		assert!(buffer.register("registry_test_dropped"));
+		assert!(find_snapshot("registry_test_dropped").is_some());
+		drop(buffer);
+		assert_eq!(find_snapshot("registry_test_dropped"), None);
+	}
+
+	#[test]
+	fn test_clone_is_not_registered() {
+		let mut buffer:CircularBuffer<i32, 8> = CircularBuffer::new();
+		assert!(buffer.register("registry_test_original"));
+		let mut clone:CircularBuffer<i32, 8> = buffer.clone();
+		clone.extend(&[1, 2, 3]);
+		assert_eq!(find_snapshot("registry_test_original").unwrap().occupancy(), 0);
+		drop(clone);
+		assert!(find_snapshot("registry_test_original").is_some());
+
+		buffer.extend(&[1]);
+		assert_eq!(find_snapshot("registry_test_original").unwrap().occupancy(), 1);
+		drop(buffer);
+		assert_eq!(find_snapshot("registry_test_original"), None);
+	}
+
+	#[test]
+	fn test_registering_again_renames_buffer() {
+		let mut buffer:CircularBuffer<i32, 8> = CircularBuffer::new();
+		assert!(buffer.register("registry_test_old_name"));
+		buffer.extend(&[1, 2]);
+		assert!(buffer.register("registry_test_new_name"));
+		assert_eq!(find_snapshot("registry_test_old_name"), None);
+		assert_eq!(find_snapshot("registry_test_new_name").unwrap().occupancy(), 2);
+	}
+}
@@ -0,0 +1,104 @@
+use crate::WaitStrategy;
+use std::{ cell::UnsafeCell, mem::MaybeUninit, ptr, sync::atomic::{ AtomicUsize, Ordering, fence } };
+
+
+
+/// Shares the latest version of a small, frequently updated value between threads, like a current transport position.
+/// Writing never waits for readers. Readers retry when the value changed while reading, so a read never returns a torn value.
+pub struct SeqLockSnapshot<T:Copy> {
+	sequence:AtomicUsize,
+	value:UnsafeCell<T>,
+	wait_strategy:WaitStrategy
+}
+impl<T:Copy> SeqLockSnapshot<T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new snapshot holding the given value. Waits by busy spinning.
+	pub const fn new(value:T) -> SeqLockSnapshot<T> {
+		SeqLockSnapshot::new_with_wait_strategy(value, WaitStrategy::BusySpin)
+	}
+
+	/// Create a new snapshot holding the given value, waiting for concurrent writes using the given strategy.
+	pub const fn new_with_wait_strategy(value:T, wait_strategy:WaitStrategy) -> SeqLockSnapshot<T> {
+		SeqLockSnapshot {
+			sequence: AtomicUsize::new(0),
+			value: UnsafeCell::new(value),
+			wait_strategy
+		}
+	}
+
+
+
+	/* WRITING METHODS */
+
+	/// Replace the value. Intended for a single writer. Concurrent writers are serialized using the wait strategy.
+	pub fn store(&self, value:T) {
+
+		// Lock by making the sequence odd. Readers seeing an odd sequence know a write is in progress.
+		let mut sequence:usize = self.sequence.load(Ordering::Relaxed);
+		let mut attempt:usize = 0;
+		loop {
+			if sequence.is_multiple_of(2) {
+				match self.sequence.compare_exchange_weak(sequence, sequence + 1, Ordering::Acquire, Ordering::Relaxed) {
+					Ok(_) => break,
+					Err(found) => sequence = found
+				}
+			} else {
+				self.wait_strategy.wait(attempt);
+				attempt += 1;
+				sequence = self.sequence.load(Ordering::Relaxed);
+			}
+		}
+		fence(Ordering::Release);
+
+		// Write the value and unlock by making the sequence even again.
+		unsafe { ptr::write_volatile(self.value.get(), value); }
+		self.sequence.store(sequence + 2, Ordering::Release);
+	}
+
+
+
+	/* READING METHODS */
+
+	/// Read the latest value, retrying using the wait strategy while a write is in progress.
+	pub fn load(&self) -> T {
+		self.wait_strategy.wait_for(|| self.try_load())
+	}
+
+	/// Try to read the latest value once. Returns None if a write was in progress during the read.
+	pub fn try_load(&self) -> Option<T> {
+		let sequence_before:usize = self.sequence.load(Ordering::Acquire);
+		if !sequence_before.is_multiple_of(2) {
+			return None;
+		}
+
+		// Read into uninitialized memory, as a torn value may not be a valid T. It only becomes a T once the sequence shows no write happened.
+		let value:MaybeUninit<T> = unsafe { ptr::read_volatile(self.value.get().cast::<MaybeUninit<T>>()) };
+		fence(Ordering::Acquire);
+		let sequence_after:usize = self.sequence.load(Ordering::Relaxed);
+		if sequence_before == sequence_after { Some(unsafe { value.assume_init() }) } else { None }
+	}
+
+	/// Get the strategy used to wait for concurrent writes.
+	pub fn wait_strategy(&self) -> WaitStrategy {
+		self.wait_strategy
+	}
+
+	/// Get the amount of times the value has been replaced.
+	pub fn version(&self) -> usize {
+		self.sequence.load(Ordering::Acquire) / 2
+	}
+
+	/// Take the value out of the snapshot.
+	pub fn into_inner(self) -> T {
+		self.value.into_inner()
+	}
+}
+impl<T:Copy + Default> Default for SeqLockSnapshot<T> {
+	fn default() -> SeqLockSnapshot<T> {
+		SeqLockSnapshot::new(T::default())
+	}
+}
+unsafe impl<T:Copy + Send> Send for SeqLockSnapshot<T> {}
+unsafe impl<T:Copy + Send> Sync for SeqLockSnapshot<T> {}
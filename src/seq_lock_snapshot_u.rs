@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ SeqLockSnapshot, WaitStrategy };
+	use std::{ num::NonZeroU32, sync::atomic::{ AtomicBool, Ordering }, thread, time::Duration };
+
+
+
+	#[test]
+	fn test_store_and_load() {
+		let snapshot:SeqLockSnapshot<(u64, u64)> = SeqLockSnapshot::new((1, 2));
+		assert_eq!(snapshot.load(), (1, 2));
+		assert_eq!(snapshot.version(), 0);
+
+		snapshot.store((3, 4));
+		snapshot.store((5, 6));
+		assert_eq!(snapshot.try_load(), Some((5, 6)));
+		assert_eq!(snapshot.version(), 2);
+		assert_eq!(snapshot.into_inner(), (5, 6));
+	}
+
+	#[test]
+	fn test_concurrent_reads_are_never_torn() {
+		for wait_strategy in [WaitStrategy::BusySpin, WaitStrategy::SpinThenYield(10), WaitStrategy::SpinThenPark(10, Duration::from_micros(10))] {
+			assert_concurrent_reads_are_never_torn(SeqLockSnapshot::new_with_wait_strategy([0; 8], wait_strategy));
+		}
+		assert_concurrent_reads_are_never_torn(SeqLockSnapshot::default());
+	}
+
+	fn assert_concurrent_reads_are_never_torn(snapshot:SeqLockSnapshot<[u64; 8]>) {
+		let done:AtomicBool = AtomicBool::new(false);
+		thread::scope(|scope| {
+			for _ in 0..3 {
+				scope.spawn(|| {
+					let mut last_value:u64 = 0;
+					while !done.load(Ordering::Relaxed) {
+						let value:[u64; 8] = snapshot.load();
+						assert!(value.iter().all(|part| *part == value[0]));
+						assert!(value[0] >= last_value);
+						last_value = value[0];
+					}
+				});
+			}
+			for value in 1..=20_000 {
+				snapshot.store([value; 8]);
+			}
+			done.store(true, Ordering::Relaxed);
+		});
+		assert_eq!(snapshot.load(), [20_000; 8]);
+	}
+
+	#[test]
+	fn test_concurrent_reads_of_types_with_invalid_bit_patterns() {
+		let snapshot:SeqLockSnapshot<(bool, char, Option<NonZeroU32>)> = SeqLockSnapshot::new((false, 'a', None));
+		let done:AtomicBool = AtomicBool::new(false);
+		thread::scope(|scope| {
+			scope.spawn(|| {
+				while !done.load(Ordering::Relaxed) {
+					let value:(bool, char, Option<NonZeroU32>) = snapshot.load();
+					assert!(value == (false, 'a', None) || value == (true, '\u{10FFFF}', NonZeroU32::new(u32::MAX)));
+				}
+			});
+			for index in 0..20_000 {
+				snapshot.store(if index % 2 == 0 { (true, '\u{10FFFF}', NonZeroU32::new(u32::MAX)) } else { (false, 'a', None) });
+			}
+			done.store(true, Ordering::Relaxed);
+		});
+	}
+}
@@ -0,0 +1,21 @@
+use std::cmp::Ordering;
+
+
+
+/// Get the index of the first sample for which the predicate returns false, for sorted samples split into two parts.
+pub(crate) fn partition_point_split<T, F:FnMut(&T) -> bool>(parts:[&[T]; 2], mut predicate:F) -> usize {
+	let first_point:usize = parts[0].partition_point(&mut predicate);
+	if first_point < parts[0].len() {
+		first_point
+	} else {
+		parts[0].len() + parts[1].partition_point(predicate)
+	}
+}
+
+/// Binary search sorted samples split into two parts. Works the same as slice::binary_search_by, with indices counting through both parts.
+pub(crate) fn binary_search_by_split<T, F:FnMut(&T) -> Ordering>(parts:[&[T]; 2], mut comparator:F) -> Result<usize, usize> {
+	match parts[0].last() {
+		Some(last) if comparator(last) != Ordering::Less => parts[0].binary_search_by(comparator),
+		_ => parts[1].binary_search_by(comparator).map(|index| parts[0].len() + index).map_err(|index| parts[0].len() + index)
+	}
+}
@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod tests {
+	use crate::sorted_search::{ binary_search_by_split, partition_point_split };
+
+
+
+	#[test]
+	fn test_partition_point_split() {
+		let samples:Vec<i32> = vec![1, 3, 5, 7, 9, 11];
+		for split in 0..=samples.len() {
+			let parts:[&[i32]; 2] = [&samples[..split], &samples[split..]];
+			assert_eq!(partition_point_split(parts, |sample| *sample < 0), 0);
+			assert_eq!(partition_point_split(parts, |sample| *sample < 6), 3);
+			assert_eq!(partition_point_split(parts, |sample| *sample <= 9), 5);
+			assert_eq!(partition_point_split(parts, |sample| *sample < 100), 6);
+		}
+	}
+
+	#[test]
+	fn test_binary_search_by_split() {
+		let samples:Vec<i32> = vec![1, 3, 5, 7, 9, 11];
+		for split in 0..=samples.len() {
+			let parts:[&[i32]; 2] = [&samples[..split], &samples[split..]];
+			for (index, sample) in samples.iter().enumerate() {
+				assert_eq!(binary_search_by_split(parts, |found| found.cmp(sample)), Ok(index));
+				assert_eq!(binary_search_by_split(parts, |found| found.cmp(&(sample + 1))), Err(index + 1));
+			}
+			assert_eq!(binary_search_by_split(parts, |found| found.cmp(&0)), Err(0));
+		}
+	}
+}
@@ -0,0 +1,91 @@
+use crate::BufferWrite;
+use std::slice::from_ref;
+
+
+
+/// Routes every written sample to one of multiple buffers, using a function that returns the index of the destination for each sample.
+/// Keeps track of the amount of samples each destination could not store.
+pub struct Splitter<'a, T, F:FnMut(&T) -> usize> {
+	destinations:Vec<&'a mut dyn BufferWrite<T>>,
+	dropped_counts:Vec<usize>,
+	unrouted_count:usize,
+	router:F
+}
+impl<'a, T, F:FnMut(&T) -> usize> Splitter<'a, T, F> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new splitter without any destinations. The router returns the index of the destination each sample should be written to.
+	pub fn new(router:F) -> Splitter<'a, T, F> {
+		Splitter {
+			destinations: Vec::new(),
+			dropped_counts: Vec::new(),
+			unrouted_count: 0,
+			router
+		}
+	}
+
+	/// Add a destination buffer. Returns the index of the destination.
+	pub fn add_destination<B:BufferWrite<T>>(&mut self, destination:&'a mut B) -> usize {
+		self.destinations.push(destination);
+		self.dropped_counts.push(0);
+		self.destinations.len() - 1
+	}
+
+
+
+	/* BUFFER METHODS */
+
+	/// Route a single sample to its destination. Returns the amount of samples stored.
+	/// Samples routed to a destination that does not exist are not stored.
+	pub fn push(&mut self, input:T) -> usize {
+		self.extend(from_ref(&input))
+	}
+
+	/// Route a list of samples to their destinations. Returns the amount of samples stored.
+	/// Samples routed to a destination that does not exist are not stored.
+	pub fn extend(&mut self, input:&[T]) -> usize {
+		let mut written:usize = 0;
+		for sample in input {
+			let destination_index:usize = (self.router)(sample);
+			match self.destinations.get_mut(destination_index) {
+				Some(destination) => {
+					let sample_written:usize = destination.extend(from_ref(sample));
+					self.dropped_counts[destination_index] += 1 - sample_written;
+					written += sample_written;
+				},
+				None => self.unrouted_count += 1
+			}
+		}
+		written
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Return the amount of destinations.
+	pub fn destination_count(&self) -> usize {
+		self.destinations.len()
+	}
+
+	/// Return the total amount of samples the destination at the given index could not store.
+	pub fn dropped_count(&self, destination_index:usize) -> usize {
+		self.dropped_counts[destination_index]
+	}
+
+	/// Return the total amount of samples each destination could not store.
+	pub fn dropped_counts(&self) -> &[usize] {
+		&self.dropped_counts
+	}
+
+	/// Return the total amount of samples that were routed to a destination that does not exist.
+	pub fn unrouted_count(&self) -> usize {
+		self.unrouted_count
+	}
+}
+impl<T, F:FnMut(&T) -> usize> BufferWrite<T> for Splitter<'_, T, F> {
+	fn extend(&mut self, input:&[T]) -> usize {
+		self.extend(input)
+	}
+}
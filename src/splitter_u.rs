@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ CircularBuffer, CircularBufferDyn, Splitter };
+
+
+
+	#[test]
+	fn test_splitter_routes_samples() {
+		let mut even:CircularBuffer<i32, 8> = CircularBuffer::new();
+		let mut odd:CircularBufferDyn<i32> = CircularBufferDyn::new(8);
+		{
+			let mut splitter = Splitter::new(|sample:&i32| (sample % 2) as usize);
+			splitter.add_destination(&mut even);
+			splitter.add_destination(&mut odd);
+			assert_eq!(splitter.destination_count(), 2);
+
+			assert_eq!(splitter.extend(&[1, 2, 3, 4, 5]), 5);
+			assert_eq!(splitter.push(6), 1);
+		}
+		assert_eq!(even.take_all(), vec![2, 4, 6]);
+		assert_eq!(odd.take_all(), vec![1, 3, 5]);
+	}
+
+	#[test]
+	fn test_splitter_reports_overflow_per_destination() {
+		let mut small:CircularBuffer<i32, 3> = CircularBuffer::new();
+		let mut large:CircularBuffer<i32, 16> = CircularBuffer::new();
+		{
+			let mut splitter = Splitter::new(|sample:&i32| if *sample < 10 { 0 } else { 1 });
+			splitter.add_destination(&mut small);
+			splitter.add_destination(&mut large);
+
+			assert_eq!(splitter.extend(&[1, 2, 3, 4, 10, 11]), 4);
+			assert_eq!(splitter.dropped_count(0), 2);
+			assert_eq!(splitter.dropped_count(1), 0);
+			assert_eq!(splitter.dropped_counts(), &[2, 0]);
+		}
+		assert_eq!(small.take_all(), vec![1, 2]);
+		assert_eq!(large.take_all(), vec![10, 11]);
+	}
+
+	#[test]
+	fn test_splitter_unrouted_samples() {
+		let mut buffer:CircularBuffer<i32, 8> = CircularBuffer::new();
+		{
+			let mut splitter = Splitter::new(|sample:&i32| *sample as usize);
+			splitter.add_destination(&mut buffer);
+
+			assert_eq!(splitter.extend(&[0, 1, 2, 0]), 2);
+			assert_eq!(splitter.unrouted_count(), 2);
+			assert_eq!(splitter.dropped_count(0), 0);
+		}
+		assert_eq!(buffer.take_all(), vec![0, 0]);
+	}
+}
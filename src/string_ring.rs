@@ -0,0 +1,84 @@
+use crate::CircularBufferSized;
+
+
+
+/// Keeps the most recent lines of text within a maximum total amount of bytes, like the last lines of a log.
+/// When a new line does not fit, the oldest lines are dropped until it does. Every line counts as at least one byte, so empty lines can not be stored without bound.
+#[derive(Clone)]
+pub struct StringRing {
+	lines:CircularBufferSized<String>,
+	evicted_count:usize
+}
+impl StringRing {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new ring that keeps lines up to the given total amount of bytes.
+	pub fn new(max_bytes:usize) -> StringRing {
+		StringRing {
+			lines: CircularBufferSized::new_byte_sized(max_bytes),
+			evicted_count: 0
+		}
+	}
+
+
+
+	/* BUFFER METHODS */
+
+	/// Add a line to the ring, dropping the oldest lines until it fits. Lines longer than the maximum amount of bytes are cut off at the last character that fits.
+	pub fn push_line<L:Into<String>>(&mut self, line:L) {
+		let mut line:String = line.into();
+		if line.len() > self.lines.size_capacity() {
+			let mut cut_index:usize = self.lines.size_capacity();
+			while !line.is_char_boundary(cut_index) {
+				cut_index -= 1;
+			}
+			line.truncate(cut_index);
+		}
+		self.evicted_count += self.lines.push_evicting(line).map(|evicted| evicted.len()).unwrap_or_default();
+	}
+
+	/// Iterate over all lines in the ring, oldest first.
+	pub fn lines(&self) -> impl Iterator<Item = &str> {
+		self.lines.iter().map(|line| line.as_str())
+	}
+
+	/// Take all lines from the ring, oldest first.
+	pub fn take_all(&mut self) -> Vec<String> {
+		self.lines.take_all()
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Return the amount of stored lines.
+	pub fn len(&self) -> usize {
+		self.lines.len()
+	}
+
+	/// Wether or not there are 0 stored lines.
+	pub fn is_empty(&self) -> bool {
+		self.lines.is_empty()
+	}
+
+	/// Return the total amount of bytes of all stored lines, counting empty lines as one byte.
+	pub fn byte_size(&self) -> usize {
+		self.lines.size()
+	}
+
+	/// Return the maximum total amount of bytes of all stored lines.
+	pub fn max_bytes(&self) -> usize {
+		self.lines.size_capacity()
+	}
+
+	/// Return the total amount of bytes held by the ring, including the content of all stored lines.
+	pub fn memory_usage(&self) -> usize {
+		size_of::<StringRing>() - size_of::<CircularBufferSized<String>>() + self.lines.memory_usage()
+	}
+
+	/// Return the total amount of lines dropped to make space for newer lines.
+	pub fn evicted_count(&self) -> usize {
+		self.evicted_count
+	}
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+	use crate::StringRing;
+
+
+
+	#[test]
+	fn test_push_and_iterate_lines() {
+		let mut ring:StringRing = StringRing::new(32);
+		ring.push_line("first");
+		ring.push_line(String::from("second"));
+		assert_eq!(ring.len(), 2);
+		assert_eq!(ring.byte_size(), 11);
+		assert_eq!(ring.lines().collect::<Vec<&str>>(), vec!["first", "second"]);
+	}
+
+	#[test]
+	fn test_drop_oldest_lines() {
+		let mut ring:StringRing = StringRing::new(16);
+		for index in 0..10 {
+			ring.push_line(format!("line {index}"));
+		}
+		assert_eq!(ring.lines().collect::<Vec<&str>>(), vec!["line 8", "line 9"]);
+		assert_eq!(ring.evicted_count(), 8);
+		assert!(ring.byte_size() <= ring.max_bytes());
+	}
+
+	#[test]
+	fn test_cut_off_long_lines() {
+		let mut ring:StringRing = StringRing::new(8);
+		ring.push_line("short");
+		ring.push_line("0123456789");
+		assert_eq!(ring.lines().collect::<Vec<&str>>(), vec!["01234567"]);
+
+		// Never cut in the middle of a character.
+		ring.push_line("ééééé");
+		assert_eq!(ring.lines().collect::<Vec<&str>>(), vec!["éééé"]);
+		assert_eq!(ring.evicted_count(), 2);
+	}
+
+	#[test]
+	fn test_take_all() {
+		let mut ring:StringRing = StringRing::new(32);
+		ring.push_line("a");
+		ring.push_line("b");
+		assert_eq!(ring.take_all(), vec![String::from("a"), String::from("b")]);
+		assert!(ring.is_empty());
+		assert_eq!(ring.byte_size(), 0);
+	}
+
+	#[test]
+	fn test_empty_lines_are_bounded() {
+		let mut ring:StringRing = StringRing::new(16);
+		for _ in 0..100_000 {
+			ring.push_line("");
+		}
+		assert_eq!(ring.len(), 16);
+		assert_eq!(ring.byte_size(), 16);
+		assert_eq!(ring.evicted_count(), 100_000 - 16);
+		assert!(ring.memory_usage() < 1024);
+	}
+}
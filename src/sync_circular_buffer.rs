@@ -0,0 +1,103 @@
+use crate::{ BufferRead, BufferWrite, CircularBufferDyn };
+use std::sync::{ Arc, Mutex, MutexGuard };
+
+
+
+/// A handle to a CircularBufferDyn that can be shared between threads. Cloning the handle shares the same buffer.
+/// All methods lock the buffer for the duration of the call. A panic in another thread while holding the lock does not make the buffer unusable, as every call leaves the buffer in a valid state.
+pub struct SyncCircularBuffer<T> {
+	buffer:Arc<Mutex<CircularBufferDyn<T>>>
+}
+impl<T:Default + Clone> SyncCircularBuffer<T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new shared circular-buffer.
+	pub fn new(capacity:usize) -> SyncCircularBuffer<T> {
+		SyncCircularBuffer::from_buffer(CircularBufferDyn::new(capacity))
+	}
+}
+impl<T:Clone> SyncCircularBuffer<T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new shared circular-buffer from an existing buffer.
+	pub fn from_buffer(buffer:CircularBufferDyn<T>) -> SyncCircularBuffer<T> {
+		SyncCircularBuffer {
+			buffer: Arc::new(Mutex::new(buffer))
+		}
+	}
+
+
+
+	/* BUFFER METHODS */
+
+	/// Add a single sample to the buffer. Returns the amount of samples stored to the buffer.
+	pub fn push(&self, input:T) -> usize {
+		self.lock().push(input)
+	}
+
+	/// Add a list of samples to the buffer. Returns the amount of samples stored to the buffer.
+	pub fn extend(&self, input:&[T]) -> usize {
+		self.lock().extend(input)
+	}
+
+	/// Take an amount of samples from the buffer.
+	pub fn take(&self, amount:usize) -> Vec<T> {
+		self.lock().take(amount)
+	}
+
+	/// Take all remaining samples from the buffer.
+	pub fn take_all(&self) -> Vec<T> {
+		self.lock().take_all()
+	}
+
+	/// Run a function on the locked buffer, for operations not available on the handle, or to combine multiple operations without another thread getting in between.
+	pub fn with_buffer<U, F:FnOnce(&mut CircularBufferDyn<T>) -> U>(&self, function:F) -> U {
+		function(&mut self.lock())
+	}
+
+	/// Lock the buffer, ignoring poisoning.
+	fn lock(&self) -> MutexGuard<'_, CircularBufferDyn<T>> {
+		self.buffer.lock().unwrap_or_else(|error| error.into_inner())
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Return the amount of currently stored samples.
+	pub fn len(&self) -> usize {
+		self.lock().len()
+	}
+
+	/// Wether or not there are 0 stored samples.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Return the amount of handles sharing the buffer.
+	pub fn handle_count(&self) -> usize {
+		Arc::strong_count(&self.buffer)
+	}
+}
+impl<T> Clone for SyncCircularBuffer<T> {
+	fn clone(&self) -> SyncCircularBuffer<T> {
+		SyncCircularBuffer {
+			buffer: Arc::clone(&self.buffer)
+		}
+	}
+}
+impl<T:Clone> BufferWrite<T> for SyncCircularBuffer<T> {
+	fn extend(&mut self, input:&[T]) -> usize {
+		SyncCircularBuffer::extend(self, input)
+	}
+}
+impl<T:Clone> BufferRead<T> for SyncCircularBuffer<T> {
+	fn len(&self) -> usize {
+		SyncCircularBuffer::len(self)
+	}
+	fn take_to_buffer(&mut self, output:&mut [T]) -> usize {
+		BufferRead::take_to_buffer(&mut *self.lock(), output)
+	}
+}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ BufferRead, SyncCircularBuffer };
+	use std::thread;
+
+
+
+	#[test]
+	fn test_handles_share_buffer() {
+		let writer:SyncCircularBuffer<i32> = SyncCircularBuffer::new(8);
+		let reader:SyncCircularBuffer<i32> = writer.clone();
+		assert_eq!(writer.handle_count(), 2);
+
+		assert_eq!(writer.extend(&[1, 2, 3]), 3);
+		assert_eq!(writer.push(4), 1);
+		assert_eq!(reader.len(), 4);
+		assert_eq!(reader.take(2), vec![1, 2]);
+		assert_eq!(reader.take_all(), vec![3, 4]);
+		assert!(writer.is_empty());
+	}
+
+	#[test]
+	fn test_threaded_producer_consumer() {
+		let buffer:SyncCircularBuffer<u32> = SyncCircularBuffer::new(16);
+		let producer:SyncCircularBuffer<u32> = buffer.clone();
+		let producer_thread = thread::spawn(move || {
+			let mut value:u32 = 0;
+			while value < 1000 {
+				value += producer.push(value) as u32;
+			}
+		});
+
+		let mut received:Vec<u32> = Vec::new();
+		while received.len() < 1000 {
+			received.extend(buffer.take(8));
+		}
+		producer_thread.join().unwrap();
+		assert_eq!(received, (0..1000).collect::<Vec<u32>>());
+	}
+
+	#[test]
+	fn test_usable_after_poisoning() {
+		let buffer:SyncCircularBuffer<i32> = SyncCircularBuffer::new(8);
+		buffer.push(1);
+		let panicking:SyncCircularBuffer<i32> = buffer.clone();
+		assert!(thread::spawn(move || panicking.with_buffer(|_| panic!("panic while locked"))).join().is_err());
+
+		buffer.push(2);
+		assert_eq!(buffer.take_all(), vec![1, 2]);
+	}
+
+	#[test]
+	fn test_buffer_read_trait() {
+		let mut buffer:SyncCircularBuffer<i32> = SyncCircularBuffer::new(8);
+		buffer.extend(&[1, 2, 3]);
+		let mut output:[i32; 2] = [0; 2];
+		assert_eq!(BufferRead::take_to_buffer(&mut buffer, &mut output), 2);
+		assert_eq!(output, [1, 2]);
+		assert_eq!(BufferRead::len(&buffer), 1);
+	}
+}
@@ -0,0 +1,71 @@
+use crate::BufferWrite;
+
+
+
+/// Duplicates every write into multiple buffers, for example a processing buffer and a recording buffer.
+/// Keeps track of the amount of samples each destination could not store.
+pub struct Tee<'a, T> {
+	destinations:Vec<&'a mut dyn BufferWrite<T>>,
+	truncated_counts:Vec<usize>
+}
+impl<'a, T> Tee<'a, T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new tee without any destinations.
+	pub fn new() -> Tee<'a, T> {
+		Tee {
+			destinations: Vec::new(),
+			truncated_counts: Vec::new()
+		}
+	}
+
+	/// Add a destination buffer. Returns the index of the destination.
+	pub fn add_destination<B:BufferWrite<T>>(&mut self, destination:&'a mut B) -> usize {
+		self.destinations.push(destination);
+		self.truncated_counts.push(0);
+		self.destinations.len() - 1
+	}
+
+
+
+	/* BUFFER METHODS */
+
+	/// Add a single sample to all destinations. Returns the amount of samples stored to each destination.
+	pub fn push(&mut self, input:T) -> Vec<usize> {
+		self.extend(&[input])
+	}
+
+	/// Add a list of samples to all destinations. Returns the amount of samples stored to each destination.
+	pub fn extend(&mut self, input:&[T]) -> Vec<usize> {
+		self.destinations.iter_mut().zip(&mut self.truncated_counts).map(|(destination, truncated_count)| {
+			let written:usize = destination.extend(input);
+			*truncated_count += input.len() - written;
+			written
+		}).collect()
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Return the amount of destinations.
+	pub fn destination_count(&self) -> usize {
+		self.destinations.len()
+	}
+
+	/// Return the total amount of samples the destination at the given index could not store.
+	pub fn truncated_count(&self, destination_index:usize) -> usize {
+		self.truncated_counts[destination_index]
+	}
+
+	/// Return the total amount of samples each destination could not store.
+	pub fn truncated_counts(&self) -> &[usize] {
+		&self.truncated_counts
+	}
+}
+impl<'a, T> Default for Tee<'a, T> {
+	fn default() -> Tee<'a, T> {
+		Tee::new()
+	}
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ CircularBuffer, CircularBufferDyn, CircularBufferMultiRead, ReadCursor, Tee };
+
+
+
+	#[test]
+	fn test_tee_duplicates_writes() {
+		let mut processing:CircularBuffer<i32, 8> = CircularBuffer::new();
+		let mut recording:CircularBufferDyn<i32> = CircularBufferDyn::new(16);
+		{
+			let mut tee:Tee<i32> = Tee::new();
+			assert_eq!(tee.add_destination(&mut processing), 0);
+			assert_eq!(tee.add_destination(&mut recording), 1);
+			assert_eq!(tee.destination_count(), 2);
+
+			assert_eq!(tee.extend(&[1, 2, 3]), vec![3, 3]);
+			assert_eq!(tee.push(4), vec![1, 1]);
+		}
+		assert_eq!(processing.take_all(), vec![1, 2, 3, 4]);
+		assert_eq!(recording.take_all(), vec![1, 2, 3, 4]);
+	}
+
+	#[test]
+	fn test_tee_reports_truncation_per_destination() {
+		let mut small:CircularBuffer<i32, 4> = CircularBuffer::new();
+		let mut large:CircularBufferMultiRead<i32, 16, 2> = CircularBufferMultiRead::new();
+		let cursor:ReadCursor = large.create_read_cursor();
+		{
+			let mut tee:Tee<i32> = Tee::new();
+			tee.add_destination(&mut small);
+			tee.add_destination(&mut large);
+
+			assert_eq!(tee.extend(&[1, 2, 3, 4, 5]), vec![3, 5]);
+			assert_eq!(tee.extend(&[6, 7]), vec![0, 2]);
+			assert_eq!(tee.truncated_count(0), 4);
+			assert_eq!(tee.truncated_count(1), 0);
+			assert_eq!(tee.truncated_counts(), &[4, 0]);
+		}
+		assert_eq!(small.take_all(), vec![1, 2, 3]);
+		assert_eq!(large.take_all(&cursor), vec![1, 2, 3, 4, 5, 6, 7]);
+	}
+}
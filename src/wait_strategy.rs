@@ -0,0 +1,187 @@
+use crate::{ Clock, SystemClock };
+use std::{ future, hint::spin_loop, sync::{ Mutex, TryLockError, atomic::{ AtomicBool, Ordering, fence } }, task::{ Poll, Waker }, thread::{ self, Thread }, time::Duration };
+
+
+
+/// Decides how a thread waits between attempts, like retrying a read or polling a buffer for data.
+/// Realtime threads, like audio threads, need to spin, while batch pipelines should give up the processor.
+/// Async tasks do not need a wait strategy, as the async methods of the concurrent buffer register the waker of the task and return Pending instead of waiting.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum WaitStrategy {
+
+	/// Spin without ever giving up the processor. Has the lowest latency.
+	BusySpin,
+
+	/// Spin for the given amount of attempts, then yield the processor to other threads on every following attempt.
+	SpinThenYield(usize),
+
+	/// Spin for the given amount of attempts, then park the thread for at most the given duration on every following attempt. Uses the least processor time.
+	/// Threads waiting for the other side of a concurrent buffer register themselves, so they are unparked as soon as that side makes progress, while other waits park for the full duration.
+	SpinThenPark(usize, Duration)
+}
+impl WaitStrategy {
+
+	/* WAITING METHODS */
+
+	/// Wait once. The attempt is the amount of times waited before for the same condition.
+	pub fn wait(&self, attempt:usize) {
+		match *self {
+			WaitStrategy::BusySpin => spin_loop(),
+			WaitStrategy::SpinThenYield(spin_count) => if attempt < spin_count { spin_loop() } else { thread::yield_now() },
+			WaitStrategy::SpinThenPark(spin_count, duration) => if attempt < spin_count { spin_loop() } else { thread::park_timeout(duration) }
+		}
+	}
+
+	/// Keep polling until the poll function returns a value, waiting between attempts.
+	pub fn wait_for<R, F:FnMut() -> Option<R>>(&self, mut poll:F) -> R {
+		let mut attempt:usize = 0;
+		loop {
+			if let Some(result) = poll() {
+				return result;
+			}
+			self.wait(attempt);
+			attempt += 1;
+		}
+	}
+
+	/// Keep polling until the poll function returns a value or the timeout passes, waiting between attempts. Returns None if the timeout passed.
+	pub fn wait_for_timeout<R, F:FnMut() -> Option<R>>(&self, timeout:Duration, poll:F) -> Option<R> {
+		self.wait_for_timeout_with_clock(timeout, &SystemClock, poll)
+	}
+
+	/// Keep polling until the poll function returns a value or the timeout passes on the given clock, waiting between attempts. Returns None if the timeout passed.
+	pub fn wait_for_timeout_with_clock<R, F:FnMut() -> Option<R>>(&self, timeout:Duration, clock:&dyn Clock, mut poll:F) -> Option<R> {
+		let deadline:Duration = clock.now() + timeout;
+		let mut attempt:usize = 0;
+		loop {
+			if let Some(result) = poll() {
+				return Some(result);
+			}
+			if clock.now() >= deadline {
+				return None;
+			}
+			self.wait(attempt);
+			attempt += 1;
+		}
+	}
+}
+impl Default for WaitStrategy {
+	fn default() -> WaitStrategy {
+		WaitStrategy::SpinThenYield(100)
+	}
+}
+
+
+
+/// A thread or task that waits for the other side of a concurrent buffer.
+#[derive(Clone)]
+enum Waiter {
+	Thread(Thread),
+	Task(Waker)
+}
+
+
+
+/// A place for a single waiting thread or task to register, so the side it waits for can wake it as soon as it made progress.
+/// Waking only locks while a waiter is registered, so a side that nobody waits for only pays for a fence and an atomic load.
+pub(crate) struct WaiterSlot {
+	registered:AtomicBool,
+	waiter:Mutex<Option<Waiter>>
+}
+impl WaiterSlot {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new slot without a waiter.
+	pub(crate) const fn new() -> WaiterSlot {
+		WaiterSlot {
+			registered: AtomicBool::new(false),
+			waiter: Mutex::new(None)
+		}
+	}
+
+
+
+	/* WAITING METHODS */
+
+	/// Keep polling until the poll function returns a value, waiting between attempts. Threads that park register themselves for the duration of the wait.
+	pub(crate) fn wait_for<R, F:FnMut() -> Option<R>>(&self, wait_strategy:WaitStrategy, poll:F) -> R {
+		let _registration:Option<Registration> = self.register_parking(wait_strategy);
+		wait_strategy.wait_for(poll)
+	}
+
+	/// Keep polling until the poll function returns a value or the timeout passes on the given clock, waiting between attempts. Returns None if the timeout passed.
+	pub(crate) fn wait_for_timeout<R, F:FnMut() -> Option<R>>(&self, wait_strategy:WaitStrategy, timeout:Duration, clock:&dyn Clock, poll:F) -> Option<R> {
+		let _registration:Option<Registration> = self.register_parking(wait_strategy);
+		wait_strategy.wait_for_timeout_with_clock(timeout, clock, poll)
+	}
+
+	/// Keep polling until the poll function returns a value, registering the waker of the task whenever it returns None.
+	/// The poll function runs again after registering, so progress made in between is not missed.
+	pub(crate) async fn wait_async<R, F:FnMut() -> Option<R>>(&self, mut poll:F) -> R {
+		let _registration:Registration = Registration(self);
+		future::poll_fn(|context| {
+			if let Some(result) = poll() {
+				return Poll::Ready(result);
+			}
+			self.register(Waiter::Task(context.waker().clone()));
+			poll().map_or(Poll::Pending, Poll::Ready)
+		}).await
+	}
+
+	/// Register the current thread if the wait strategy parks it.
+	fn register_parking(&self, wait_strategy:WaitStrategy) -> Option<Registration<'_>> {
+		matches!(wait_strategy, WaitStrategy::SpinThenPark(..)).then(|| {
+			self.register(Waiter::Thread(thread::current()));
+			Registration(self)
+		})
+	}
+
+	/// Register a waiter, replacing any earlier one. The fence makes sure either the waiting side sees the progress of the other side when polling again, or the other side sees the registration.
+	fn register(&self, waiter:Waiter) {
+		*self.waiter.lock().unwrap_or_else(|error| error.into_inner()) = Some(waiter);
+		self.registered.store(true, Ordering::Relaxed);
+		fence(Ordering::SeqCst);
+	}
+
+
+
+	/* WAKING METHODS */
+
+	/// Wake the registered waiters of all slots. Needs to be called after making progress the waiters could be waiting for.
+	pub(crate) fn wake_all(slots:&[WaiterSlot]) {
+		fence(Ordering::SeqCst);
+		for slot in slots {
+			if slot.registered.load(Ordering::Relaxed) {
+				slot.wake_registered();
+			}
+		}
+	}
+
+	/// Wake the registered waiter, if any. If the waiter is busy registering, it polls again afterwards, so it does not need to be woken.
+	fn wake_registered(&self) {
+		let waiter:Option<Waiter> = match self.waiter.try_lock() {
+			Ok(waiter) => waiter.clone(),
+			Err(TryLockError::Poisoned(error)) => error.into_inner().clone(),
+			Err(TryLockError::WouldBlock) => return
+		};
+
+		// The lock is released before waking, as a task may be polled again right away.
+		match waiter {
+			Some(Waiter::Thread(thread)) => thread.unpark(),
+			Some(Waiter::Task(waker)) => waker.wake(),
+			None => {}
+		}
+	}
+}
+
+
+
+/// Removes the waiter from the slot once the wait is over, including when the wait is cancelled by dropping a future.
+struct Registration<'a>(&'a WaiterSlot);
+impl Drop for Registration<'_> {
+	fn drop(&mut self) {
+		self.0.registered.store(false, Ordering::Relaxed);
+		*self.0.waiter.lock().unwrap_or_else(|error| error.into_inner()) = None;
+	}
+}
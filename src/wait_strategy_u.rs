@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ CircularBufferDyn, ManualClock, WaitStrategy };
+	use std::{ sync::Mutex, thread, time::Duration };
+
+
+
+	#[test]
+	fn test_wait_for_polls_until_ready() {
+		for strategy in [WaitStrategy::BusySpin, WaitStrategy::SpinThenYield(2), WaitStrategy::SpinThenPark(2, Duration::from_micros(10))] {
+			let mut attempts:usize = 0;
+			let result:usize = strategy.wait_for(|| {
+				attempts += 1;
+				if attempts == 10 { Some(attempts) } else { None }
+			});
+			assert_eq!(result, 10);
+		}
+	}
+
+	#[test]
+	fn test_wait_for_timeout() {
+		let strategy:WaitStrategy = WaitStrategy::SpinThenPark(10, Duration::from_millis(1));
+		assert_eq!(strategy.wait_for_timeout(Duration::from_millis(5), || None::<()>), None);
+		assert_eq!(strategy.wait_for_timeout(Duration::from_millis(5), || Some(3)), Some(3));
+	}
+
+	#[test]
+	fn test_wait_for_timeout_with_clock() {
+		let clock:ManualClock = ManualClock::new();
+		let mut attempts:usize = 0;
+		let result:Option<()> = WaitStrategy::BusySpin.wait_for_timeout_with_clock(Duration::from_secs(1), &clock, || {
+			attempts += 1;
+			clock.advance(Duration::from_millis(100));
+			None
+		});
+		assert_eq!(result, None);
+		assert_eq!(attempts, 10);
+	}
+
+	#[test]
+	fn test_wait_for_buffer_batch() {
+		let buffer:Mutex<CircularBufferDyn<i32>> = Mutex::new(CircularBufferDyn::new(16));
+		thread::scope(|scope| {
+			scope.spawn(|| {
+				for value in 0..8 {
+					buffer.lock().unwrap().push(value);
+					thread::sleep(Duration::from_millis(1));
+				}
+			});
+			let batch:Vec<i32> = WaitStrategy::default().wait_for(|| buffer.lock().unwrap().take_at_least(8, 8));
+			assert_eq!(batch, (0..8).collect::<Vec<i32>>());
+		});
+	}
+}
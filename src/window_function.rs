@@ -0,0 +1,64 @@
+use std::f64::consts::PI;
+
+
+
+/// A sample type that can be multiplied by a window function coefficient.
+pub trait WindowSample:Copy {
+
+	/// Multiply the sample by the given factor.
+	fn scale(self, factor:f64) -> Self;
+}
+impl WindowSample for f32 {
+	fn scale(self, factor:f64) -> f32 {
+		(self as f64 * factor) as f32
+	}
+}
+impl WindowSample for f64 {
+	fn scale(self, factor:f64) -> f64 {
+		self * factor
+	}
+}
+
+
+
+/// A window function that can be applied to samples while taking them from a buffer, as used in spectral pipelines.
+/// All windows are symmetric, meaning the first and last coefficient are equal.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum WindowFunction {
+	Rectangular,
+	Hann,
+	Hamming,
+	Blackman
+}
+impl WindowFunction {
+
+	/// Get the coefficient of the window function at the given index in a window of the given size.
+	pub fn coefficient(&self, index:usize, window:usize) -> f64 {
+		if window <= 1 {
+			return 1.0;
+		}
+		let phase:f64 = 2.0 * PI * index as f64 / (window - 1) as f64;
+		match self {
+			WindowFunction::Rectangular => 1.0,
+			WindowFunction::Hann => 0.5 - 0.5 * phase.cos(),
+			WindowFunction::Hamming => 0.54 - 0.46 * phase.cos(),
+			WindowFunction::Blackman => 0.42 - 0.5 * phase.cos() + 0.08 * (2.0 * phase).cos()
+		}
+	}
+
+	/// Multiply all samples by the window function in place.
+	pub fn apply<T:WindowSample>(&self, samples:&mut [T]) {
+		let window:usize = samples.len();
+		for (index, sample) in samples.iter_mut().enumerate() {
+			*sample = sample.scale(self.coefficient(index, window));
+		}
+	}
+
+	/// Write the samples of both source parts to the output, multiplied by the window function. The output should be as long as both sources combined.
+	pub(crate) fn apply_to_output<T:WindowSample>(&self, sources:[&[T]; 2], output:&mut [T]) {
+		let window:usize = output.len();
+		for (index, (output_sample, source_sample)) in output.iter_mut().zip(sources[0].iter().chain(sources[1])).enumerate() {
+			*output_sample = source_sample.scale(self.coefficient(index, window));
+		}
+	}
+}
@@ -0,0 +1,39 @@
+#[cfg(test)]
+#[cfg(feature = "dsp")]
+mod tests {
+	use crate::WindowFunction;
+
+
+
+	fn assert_close(found:&[f64], expected:&[f64]) {
+		assert_eq!(found.len(), expected.len());
+		for (found, expected) in found.iter().zip(expected) {
+			assert!((found - expected).abs() < 1e-9, "{found} != {expected}");
+		}
+	}
+
+	#[test]
+	fn test_window_coefficients() {
+		let coefficients = |function:WindowFunction| (0..5).map(|index| function.coefficient(index, 5)).collect::<Vec<f64>>();
+		assert_close(&coefficients(WindowFunction::Rectangular), &[1.0, 1.0, 1.0, 1.0, 1.0]);
+		assert_close(&coefficients(WindowFunction::Hann), &[0.0, 0.5, 1.0, 0.5, 0.0]);
+		assert_close(&coefficients(WindowFunction::Hamming), &[0.08, 0.54, 1.0, 0.54, 0.08]);
+		assert_close(&coefficients(WindowFunction::Blackman), &[0.0, 0.34, 1.0, 0.34, 0.0]);
+	}
+
+	#[test]
+	fn test_window_single_sample() {
+		assert_eq!(WindowFunction::Hann.coefficient(0, 1), 1.0);
+	}
+
+	#[test]
+	fn test_window_apply() {
+		let mut samples:[f64; 5] = [2.0; 5];
+		WindowFunction::Hann.apply(&mut samples);
+		assert_close(&samples, &[0.0, 1.0, 2.0, 1.0, 0.0]);
+
+		let mut samples:[f32; 3] = [4.0; 3];
+		WindowFunction::Hann.apply(&mut samples);
+		assert_eq!(samples, [0.0, 4.0, 0.0]);
+	}
+}
@@ -0,0 +1,10 @@
+use crate::BufferRead;
+
+
+
+/// Take pairs of samples from two buffers in lockstep, for example to align left and right audio channels.
+/// Only takes as many pairs as both buffers have available, up to the given amount, so no sample is taken without its counterpart.
+pub fn take_zipped<T:Default + Clone, U:Default + Clone, A:BufferRead<T> + ?Sized, B:BufferRead<U> + ?Sized>(first:&mut A, second:&mut B, amount:usize) -> Vec<(T, U)> {
+	let pair_count:usize = amount.min(first.len()).min(second.len());
+	first.take(pair_count).into_iter().zip(second.take(pair_count)).collect()
+}
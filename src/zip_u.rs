@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ CircularBuffer, CircularBufferDyn, take_zipped };
+
+
+
+	#[test]
+	fn test_take_zipped_limited_by_shortest() {
+		let mut left:CircularBuffer<f32, 8> = CircularBuffer::new();
+		let mut right:CircularBufferDyn<f32> = CircularBufferDyn::new(8);
+		left.extend(&[0.1, 0.2, 0.3]);
+		right.extend(&[1.1, 1.2]);
+
+		assert_eq!(take_zipped(&mut left, &mut right, 10), vec![(0.1, 1.1), (0.2, 1.2)]);
+		assert_eq!(left.len(), 1);
+		assert!(right.is_empty());
+		assert_eq!(take_zipped(&mut left, &mut right, 10), vec![]);
+		assert_eq!(left.len(), 1);
+	}
+
+	#[test]
+	fn test_take_zipped_limited_by_amount() {
+		let mut samples:CircularBuffer<i32, 8> = CircularBuffer::new();
+		let mut timestamps:CircularBuffer<u64, 8> = CircularBuffer::new();
+		samples.extend(&[1, 2, 3, 4]);
+		timestamps.extend(&[10, 20, 30, 40]);
+
+		assert_eq!(take_zipped(&mut samples, &mut timestamps, 3), vec![(1, 10), (2, 20), (3, 30)]);
+		assert_eq!(take_zipped(&mut samples, &mut timestamps, 3), vec![(4, 40)]);
+	}
+}